@@ -0,0 +1,115 @@
+//! Compares tight loops over `Vec<CustomerId>` against the same loops over `Vec<u32>`.
+//!
+//! `TypedId` is a `u32` plus a zero-sized `PhantomData`, and every forwarding method is
+//! `#[inline]`, so each pair of benchmarks should compile down to identical code and report the
+//! same timings.
+//!
+//! The comparison forwarders are `#[inline(always)]`, so the `equality` and `compare` pairs should
+//! also match at low optimization levels. To check, run
+//! `CARGO_PROFILE_BENCH_OPT_LEVEL=1 cargo bench --bench zero_cost`.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    hint::black_box,
+};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use typed_id::TypedId;
+
+struct Customer;
+
+type CustomerId = TypedId<u32, Customer>;
+
+const LEN: u32 = 10_000;
+
+fn sum(c: &mut Criterion) {
+    let raw: Vec<u32> = (0..LEN).collect();
+    let typed: Vec<CustomerId> = (0..LEN).map(Into::into).collect();
+
+    let mut group = c.benchmark_group("sum");
+    group.bench_function("u32", |b| {
+        b.iter(|| {
+            black_box(&raw)
+                .iter()
+                .fold(0u32, |acc, id| acc.wrapping_add(*id))
+        })
+    });
+    group.bench_function("TypedId", |b| {
+        b.iter(|| {
+            black_box(&typed)
+                .iter()
+                .fold(0u32, |acc, id| acc.wrapping_add(**id))
+        })
+    });
+    group.finish();
+}
+
+fn compare(c: &mut Criterion) {
+    let raw: Vec<u32> = (0..LEN).collect();
+    let typed: Vec<CustomerId> = (0..LEN).map(Into::into).collect();
+    let raw_target = LEN / 2;
+    let typed_target = CustomerId::new(LEN / 2);
+
+    let mut group = c.benchmark_group("compare");
+    group.bench_function("u32", |b| {
+        b.iter(|| {
+            let target = black_box(raw_target);
+            black_box(&raw).iter().filter(|id| **id < target).count()
+        })
+    });
+    group.bench_function("TypedId", |b| {
+        b.iter(|| {
+            let target = black_box(typed_target);
+            black_box(&typed).iter().filter(|id| **id < target).count()
+        })
+    });
+    group.finish();
+}
+
+fn equality(c: &mut Criterion) {
+    let raw: Vec<u32> = (0..LEN).map(|i| i % 16).collect();
+    let typed: Vec<CustomerId> = (0..LEN).map(|i| CustomerId::new(i % 16)).collect();
+    let raw_target = 7;
+    let typed_target = CustomerId::new(7);
+
+    let mut group = c.benchmark_group("equality");
+    group.bench_function("u32", |b| {
+        b.iter(|| {
+            let target = black_box(raw_target);
+            black_box(&raw).iter().filter(|id| **id == target).count()
+        })
+    });
+    group.bench_function("TypedId", |b| {
+        b.iter(|| {
+            let target = black_box(typed_target);
+            black_box(&typed).iter().filter(|id| **id == target).count()
+        })
+    });
+    group.finish();
+}
+
+fn hash(c: &mut Criterion) {
+    let raw: Vec<u32> = (0..LEN).collect();
+    let typed: Vec<CustomerId> = (0..LEN).map(Into::into).collect();
+
+    let mut group = c.benchmark_group("hash");
+    group.bench_function("u32", |b| {
+        b.iter(|| {
+            let mut hasher = DefaultHasher::new();
+            black_box(&raw).iter().for_each(|id| id.hash(&mut hasher));
+            hasher.finish()
+        })
+    });
+    group.bench_function("TypedId", |b| {
+        b.iter(|| {
+            let mut hasher = DefaultHasher::new();
+            black_box(&typed).iter().for_each(|id| id.hash(&mut hasher));
+            hasher.finish()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, sum, compare, equality, hash);
+criterion_main!(benches);
@@ -0,0 +1,103 @@
+//! This module contains `AnyId`, a type-erased view of a typed id that still respects markers.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    hash::{Hash, Hasher},
+};
+
+use crate::TypedId;
+
+/// A `dyn`-safe trait implemented for all `TypedId`s, allowing ids of many entity types to be
+/// stored together (e.g. as `Box<dyn AnyId>`).
+///
+/// Equality and hashing of `dyn AnyId` take the marker type into account, so a `CustomerId` and
+/// an `OrderId` are never equal, even if their underlying ids are.
+/// ```rust
+/// # struct Customer;
+/// # struct Order;
+/// use std::collections::HashSet;
+/// use typed_id::{AnyId, TypedId};
+///
+/// let mut ids: HashSet<Box<dyn AnyId>> = HashSet::new();
+/// ids.insert(Box::new(TypedId::<u32, Customer>::new(42)));
+/// ids.insert(Box::new(TypedId::<u32, Order>::new(42)));
+/// assert_eq!(ids.len(), 2);
+/// ```
+pub trait AnyId: Any {
+    /// Returns the `TypeId` of the marker type
+    fn marker_type_id(&self) -> TypeId;
+
+    /// Returns this id as a `dyn Any`, so that it can be downcast back into a concrete `TypedId`
+    fn as_any(&self) -> &dyn Any;
+
+    /// Compares this id to another type-erased id
+    fn dyn_eq(&self, other: &dyn AnyId) -> bool;
+
+    /// Feeds this id's underlying id into the given hasher
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+}
+
+impl<I: Hash + Eq + 'static, T: 'static> AnyId for TypedId<I, T> {
+    fn marker_type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn AnyId) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        self.0.hash(&mut state)
+    }
+}
+
+impl PartialEq for dyn AnyId {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other)
+    }
+}
+
+impl Eq for dyn AnyId {}
+
+impl Hash for dyn AnyId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.marker_type_id().hash(state);
+        self.dyn_hash(state)
+    }
+}
+
+/// Partitions a mixed collection of type-erased ids by the `TypeId` of their markers. Within each
+/// group, ids keep the order in which they were given.
+/// ```rust
+/// # struct Customer;
+/// # struct Order;
+/// use std::any::TypeId;
+/// use typed_id::{group_by_type, AnyId, TypedId};
+///
+/// let ids: Vec<Box<dyn AnyId>> = vec![
+///     Box::new(TypedId::<u32, Customer>::new(1)),
+///     Box::new(TypedId::<u32, Order>::new(2)),
+///     Box::new(TypedId::<u32, Customer>::new(3)),
+/// ];
+/// let groups = group_by_type(ids);
+/// assert_eq!(groups[&TypeId::of::<Customer>()].len(), 2);
+/// assert_eq!(groups[&TypeId::of::<Order>()].len(), 1);
+/// ```
+pub fn group_by_type<Iter>(ids: Iter) -> HashMap<TypeId, Vec<Box<dyn AnyId>>>
+where
+    Iter: IntoIterator<Item = Box<dyn AnyId>>,
+{
+    let mut groups: HashMap<TypeId, Vec<Box<dyn AnyId>>> = HashMap::new();
+    for id in ids {
+        groups.entry(id.marker_type_id()).or_default().push(id);
+    }
+    groups
+}
@@ -0,0 +1,70 @@
+//! This module allows integer ids to be aggregated into their underlying type.
+//!
+//! An aggregate of ids, like a checksum, isn't an id itself, so summing or multiplying typed ids
+//! produces the raw underlying integer.
+//! ```rust
+//! # struct Customer;
+//! use typed_id::TypedId;
+//! let ids: Vec<TypedId<u32, Customer>> = vec![1.into(), 2.into(), 3.into()];
+//! assert_eq!(ids.iter().sum::<u32>(), 6);
+//! assert_eq!(ids.into_iter().product::<u32>(), 6);
+//! ```
+//!
+//! The orphan rules don't allow a blanket `impl<I: Sum, T> Sum<TypedId<I, T>> for I`, so these are
+//! implemented for std's integer types.
+
+use std::iter::{Product, Sum};
+
+use crate::TypedId;
+
+macro_rules! impl_aggregates {
+    ($($inner:ty),* $(,)?) => {
+        $(
+            impl<T> Sum<TypedId<$inner, T>> for $inner {
+                #[inline]
+                fn sum<It: Iterator<Item = TypedId<$inner, T>>>(iter: It) -> Self {
+                    iter.map(|id| id.0).sum()
+                }
+            }
+
+            impl<'a, T> Sum<&'a TypedId<$inner, T>> for $inner {
+                #[inline]
+                fn sum<It: Iterator<Item = &'a TypedId<$inner, T>>>(iter: It) -> Self {
+                    iter.map(|id| id.0).sum()
+                }
+            }
+
+            impl<T> Product<TypedId<$inner, T>> for $inner {
+                #[inline]
+                fn product<It: Iterator<Item = TypedId<$inner, T>>>(iter: It) -> Self {
+                    iter.map(|id| id.0).product()
+                }
+            }
+
+            impl<'a, T> Product<&'a TypedId<$inner, T>> for $inner {
+                #[inline]
+                fn product<It: Iterator<Item = &'a TypedId<$inner, T>>>(iter: It) -> Self {
+                    iter.map(|id| id.0).product()
+                }
+            }
+        )*
+    };
+}
+
+impl_aggregates!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::TypedId;
+
+    struct Customer;
+
+    #[test]
+    fn sum_into_raw() {
+        let ids: Vec<TypedId<u32, Customer>> = (1..=4).map(TypedId::new).collect();
+        let total: u32 = ids.iter().sum();
+        assert_eq!(total, 10);
+        let product: u32 = ids.into_iter().product();
+        assert_eq!(product, 24);
+    }
+}
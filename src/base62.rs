@@ -0,0 +1,136 @@
+//! This module contains the base62 encoding of integer ids, as used for short shareable links.
+//!
+//! Digits are ordered `0-9`, then `A-Z`, then `a-z`, so the encoding of a larger id never sorts
+//! before the encoding of a smaller id of the same length.
+//! ```rust
+//! # struct Link;
+//! use typed_id::TypedId;
+//! type LinkId = TypedId<u64, Link>;
+//!
+//! let id = LinkId::new(62_000);
+//! assert_eq!(id.to_base62(), "G80");
+//! assert_eq!(LinkId::from_base62("G80"), Ok(id));
+//! ```
+
+use std::fmt;
+
+use crate::TypedId;
+
+const ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+fn digit_value(c: char) -> Option<u8> {
+    match c {
+        '0'..='9' => Some(c as u8 - b'0'),
+        'A'..='Z' => Some(c as u8 - b'A' + 10),
+        'a'..='z' => Some(c as u8 - b'a' + 36),
+        _ => None,
+    }
+}
+
+macro_rules! impl_base62 {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl<T> TypedId<$int, T> {
+                /// Encodes the underlying id in base62. Zero is encoded as `"0"`.
+                pub fn to_base62(&self) -> String {
+                    let mut num = self.0;
+                    let mut digits = Vec::new();
+                    loop {
+                        digits.push(ALPHABET[(num % 62) as usize]);
+                        num /= 62;
+                        if num == 0 {
+                            break;
+                        }
+                    }
+                    digits.reverse();
+                    // Every digit comes from the ASCII alphabet
+                    String::from_utf8(digits).unwrap()
+                }
+
+                /// Decodes an id from its base62 encoding, as produced by `to_base62`.
+                pub fn from_base62(s: &str) -> Result<Self, ParseBase62Error> {
+                    if s.is_empty() {
+                        return Err(ParseBase62Error::Empty);
+                    }
+                    let mut num: $int = 0;
+                    for c in s.chars() {
+                        let digit = digit_value(c).ok_or(ParseBase62Error::InvalidDigit(c))?;
+                        num = num
+                            .checked_mul(62)
+                            .and_then(|num| num.checked_add(digit as $int))
+                            .ok_or(ParseBase62Error::Overflow)?;
+                    }
+                    Ok(Self::new(num))
+                }
+            }
+        )*
+    };
+}
+
+impl_base62!(u8, u16, u32, u64, u128, usize);
+
+/// The error returned by `TypedId::from_base62`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBase62Error {
+    /// The string was empty
+    Empty,
+    /// The string contained a character that is not a base62 digit
+    InvalidDigit(char),
+    /// The encoded id does not fit in the underlying id type
+    Overflow,
+}
+
+impl fmt::Display for ParseBase62Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseBase62Error::Empty => f.write_str("cannot parse an id from an empty string"),
+            ParseBase62Error::InvalidDigit(c) => write!(f, "invalid base62 digit {c:?}"),
+            ParseBase62Error::Overflow => f.write_str("base62 id is too large for its type"),
+        }
+    }
+}
+
+impl std::error::Error for ParseBase62Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseBase62Error;
+    use crate::TypedId;
+
+    struct Link;
+
+    type LinkId = TypedId<u64, Link>;
+
+    #[test]
+    fn round_trip() {
+        for raw in [0, 1, 61, 62, 3843, 3844, 1_000_000, u64::MAX] {
+            let id = LinkId::new(raw);
+            assert_eq!(LinkId::from_base62(&id.to_base62()), Ok(id));
+        }
+        assert_eq!(LinkId::new(0).to_base62(), "0");
+        assert_eq!(LinkId::new(61).to_base62(), "z");
+        assert_eq!(LinkId::new(62).to_base62(), "10");
+        assert_eq!(LinkId::new(u64::MAX).to_base62(), "LygHa16AHYF");
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        assert_eq!(LinkId::from_base62(""), Err(ParseBase62Error::Empty));
+        assert_eq!(
+            LinkId::from_base62("ab-c"),
+            Err(ParseBase62Error::InvalidDigit('-'))
+        );
+        assert_eq!(
+            LinkId::from_base62(" 1"),
+            Err(ParseBase62Error::InvalidDigit(' '))
+        );
+        assert_eq!(
+            LinkId::from_base62("LygHa16AHYG"),
+            Err(ParseBase62Error::Overflow)
+        );
+        assert_eq!(
+            TypedId::<u8, Link>::from_base62("48"),
+            Err(ParseBase62Error::Overflow)
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! This implements bincode's native `Encode`, `Decode`, and `BorrowDecode` traits for all `TypedId`
+//! whose underlying type implements them. Like with serde, `TypedId`s are encoded as their
+//! underlying type, so ids can be used in bincode containers without going through serde.
+
+use bincode::{
+    de::{BorrowDecoder, Decoder},
+    enc::Encoder,
+    error::{DecodeError, EncodeError},
+    BorrowDecode, Decode, Encode,
+};
+
+use crate::TypedId;
+
+impl<I: Encode, T> Encode for TypedId<I, T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.0.encode(encoder)
+    }
+}
+
+impl<Context, I: Decode<Context>, T> Decode<Context> for TypedId<I, T> {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        I::decode(decoder).map(TypedId::new)
+    }
+}
+
+impl<'de, Context, I: BorrowDecode<'de, Context>, T> BorrowDecode<'de, Context> for TypedId<I, T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        I::borrow_decode(decoder).map(TypedId::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bincode::config;
+
+    use crate::TypedId;
+
+    type CustomerId = TypedId<u32, Customer>;
+
+    struct Customer;
+
+    #[test]
+    fn round_trip() {
+        let id: CustomerId = 42.into();
+        let bytes = bincode::encode_to_vec(id, config::standard()).unwrap();
+        assert_eq!(
+            bytes,
+            bincode::encode_to_vec(42u32, config::standard()).unwrap()
+        );
+        let (new_id, len): (CustomerId, _) =
+            bincode::decode_from_slice(&bytes, config::standard()).unwrap();
+        assert_eq!(new_id, id);
+        assert_eq!(len, bytes.len());
+
+        let ids: Vec<TypedId<String, Customer>> =
+            vec!["a".to_owned().into(), "bc".to_owned().into()];
+        let bytes = bincode::encode_to_vec(&ids, config::legacy()).unwrap();
+        let raw = vec!["a".to_owned(), "bc".to_owned()];
+        assert_eq!(
+            bytes,
+            bincode::encode_to_vec(&raw, config::legacy()).unwrap()
+        );
+        let (new_ids, _): (Vec<TypedId<String, Customer>>, _) =
+            bincode::decode_from_slice(&bytes, config::legacy()).unwrap();
+        assert_eq!(new_ids, ids);
+
+        let (slug, _): (TypedId<&str, Customer>, _) =
+            bincode::borrow_decode_from_slice(&bytes[8..], config::legacy()).unwrap();
+        assert_eq!(*slug, "a");
+    }
+}
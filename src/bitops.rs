@@ -0,0 +1,88 @@
+//! This module adds bitwise operators for ids that pack several fields into one integer, like
+//! region bits and entity bits.
+//!
+//! A masked id is still an id of the same entity, so these operators produce typed ids and keep
+//! the marker type. Operands can be typed ids or raw masks of the underlying type.
+//! ```rust
+//! # struct Entity;
+//! use typed_id::TypedId;
+//! let region: TypedId<u32, Entity> = 0xAB00.into();
+//! let local: TypedId<u32, Entity> = 0x0042.into();
+//! let id = region | local;
+//! assert_eq!(*id, 0xAB42);
+//! assert_eq!(*id.mask(0x00FF), 0x0042);
+//! ```
+
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use crate::TypedId;
+
+macro_rules! impl_bitop {
+    ($($op:ident::$method:ident),* $(,)?) => {
+        $(
+            impl<I: $op<Output = I>, T> $op for TypedId<I, T> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    Self::new(self.0.$method(rhs.0))
+                }
+            }
+
+            impl<I: $op<Output = I>, T> $op<I> for TypedId<I, T> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: I) -> Self {
+                    Self::new(self.0.$method(rhs))
+                }
+            }
+        )*
+    };
+}
+
+impl_bitop!(BitAnd::bitand, BitOr::bitor, BitXor::bitxor);
+
+impl<I: Not<Output = I>, T> Not for TypedId<I, T> {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self::new(!self.0)
+    }
+}
+
+impl<I: BitAnd<Output = I>, T> TypedId<I, T> {
+    /// Keeps only the bits of the id that are set in `mask`
+    #[inline]
+    pub fn mask(self, mask: I) -> Self {
+        self & mask
+    }
+}
+
+impl<I: BitAnd<Output = I> + Not<Output = I>, T> TypedId<I, T> {
+    /// Clears the bits of the id that are set in `mask`
+    #[inline]
+    pub fn clear_bits(self, mask: I) -> Self {
+        self & !mask
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TypedId;
+
+    struct Entity;
+
+    type EntityId = TypedId<u32, Entity>;
+
+    #[test]
+    fn or_and_mask() {
+        let id = EntityId::new(0xAB00) | EntityId::new(0x0042);
+        assert_eq!(id, EntityId::new(0xAB42));
+        assert_eq!(id.mask(0xFF00), EntityId::new(0xAB00));
+        assert_eq!(id.clear_bits(0xFF00), EntityId::new(0x0042));
+        assert_eq!(id ^ 0x0042, EntityId::new(0xAB00));
+        assert_eq!(!EntityId::new(0), EntityId::new(u32::MAX));
+    }
+}
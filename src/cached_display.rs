@@ -0,0 +1,126 @@
+//! This module contains `CachedDisplayId`, a wrapper for ids that are formatted repeatedly.
+
+use std::{cell::OnceCell, fmt, hash::Hash, ops::Deref};
+
+use crate::TypedId;
+
+/// A typed id that memoizes its `Display` output the first time it is formatted. This trades a
+/// small allocation for avoiding repeated conversions, like integer-to-string, when the same id is
+/// formatted many times.
+///
+/// The cache is a `OnceCell`, so this wrapper is not `Sync`.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{CachedDisplayId, TypedId};
+/// let id: CachedDisplayId<u32, Customer> = TypedId::new(42).into();
+/// assert_eq!(id.as_str(), "42");
+/// assert_eq!(format!("{id:>4}"), "  42");
+/// assert_eq!(**id, 42);
+/// ```
+pub struct CachedDisplayId<I, T> {
+    id: TypedId<I, T>,
+    display: OnceCell<Box<str>>,
+}
+
+impl<I, T> CachedDisplayId<I, T> {
+    /// Creates a new cached id with an underlying ID type of `I`
+    pub const fn new(id: I) -> Self {
+        Self {
+            id: TypedId::new(id),
+            display: OnceCell::new(),
+        }
+    }
+
+    /// Consumes this wrapper, returning the typed id
+    pub fn into_inner(self) -> TypedId<I, T> {
+        self.id
+    }
+}
+
+impl<I: fmt::Display, T> CachedDisplayId<I, T> {
+    /// Returns the formatted id, formatting and caching it if this is the first call
+    pub fn as_str(&self) -> &str {
+        self.display
+            .get_or_init(|| self.id.to_string().into_boxed_str())
+    }
+}
+
+impl<I: fmt::Debug, T> fmt::Debug for CachedDisplayId<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.id.fmt(f)
+    }
+}
+
+impl<I: fmt::Display, T> fmt::Display for CachedDisplayId<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl<I: Clone, T> Clone for CachedDisplayId<I, T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            display: self.display.clone(),
+        }
+    }
+}
+
+impl<I: Hash, T> Hash for CachedDisplayId<I, T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+impl<I: PartialEq, T> PartialEq for CachedDisplayId<I, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id.eq(&other.id)
+    }
+}
+
+impl<I: Eq, T> Eq for CachedDisplayId<I, T> {}
+
+impl<I, T> Deref for CachedDisplayId<I, T> {
+    type Target = TypedId<I, T>;
+    fn deref(&self) -> &Self::Target {
+        &self.id
+    }
+}
+
+impl<I, T> From<TypedId<I, T>> for CachedDisplayId<I, T> {
+    fn from(other: TypedId<I, T>) -> Self {
+        Self {
+            id: other,
+            display: OnceCell::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, fmt};
+
+    use super::CachedDisplayId;
+
+    struct Customer;
+
+    struct Counted<'a>(&'a Cell<usize>);
+
+    impl fmt::Display for Counted<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            f.write_str("counted")
+        }
+    }
+
+    #[test]
+    fn formats_once() {
+        let calls = Cell::new(0);
+        let id: CachedDisplayId<Counted<'_>, Customer> = CachedDisplayId::new(Counted(&calls));
+        assert_eq!(calls.get(), 0);
+        for _ in 0..3 {
+            assert_eq!(id.to_string(), "counted");
+        }
+        assert_eq!(calls.get(), 1);
+    }
+}
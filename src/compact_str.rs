@@ -0,0 +1,52 @@
+//! This module adds conversions for `TypedId`s whose underlying type is a `CompactString`.
+
+use compact_str::CompactString;
+
+use crate::TypedId;
+
+impl<T> From<&str> for TypedId<CompactString, T> {
+    fn from(other: &str) -> Self {
+        Self::new(other.into())
+    }
+}
+
+impl<T> From<String> for TypedId<CompactString, T> {
+    fn from(other: String) -> Self {
+        Self::new(other.into())
+    }
+}
+
+impl<T> From<TypedId<String, T>> for TypedId<CompactString, T> {
+    fn from(other: TypedId<String, T>) -> Self {
+        Self::new(other.0.into())
+    }
+}
+
+impl<T> From<TypedId<CompactString, T>> for TypedId<String, T> {
+    fn from(other: TypedId<CompactString, T>) -> Self {
+        Self::new(other.0.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use compact_str::CompactString;
+
+    use crate::TypedId;
+
+    type CustomerId = TypedId<CompactString, Customer>;
+
+    struct Customer;
+
+    #[test]
+    fn string_conversions() {
+        let id: CustomerId = "jane-doe".into();
+        assert_eq!(id.to_string(), "jane-doe");
+        assert_eq!(id, "jane-doe".parse().unwrap());
+
+        let owned: TypedId<String, Customer> = id.clone().into();
+        assert_eq!(*owned, "jane-doe");
+        assert_eq!(CustomerId::from(owned), id);
+        assert_eq!(CustomerId::from(String::from("jane-doe")), id);
+    }
+}
@@ -0,0 +1,100 @@
+//! This module adds helpers for composite ids, whose underlying type is a two-part tuple like
+//! `(tenant, local_id)`.
+//!
+//! Composite ids are written as their parts separated by a colon, e.g. `7:42`. Note, `Display` and
+//! `FromStr` can not be implemented for `TypedId<(A, B), T>` since they would overlap with the
+//! impls for all ids whose underlying type implements them. Use `display_composite` and
+//! `parse_composite` instead.
+
+use std::{fmt, str::FromStr};
+
+use crate::TypedId;
+
+impl<A, B, T> TypedId<(A, B), T> {
+    /// Returns the first part of the composite id
+    #[inline]
+    pub fn part0(&self) -> &A {
+        &self.0 .0
+    }
+
+    /// Returns the second part of the composite id
+    #[inline]
+    pub fn part1(&self) -> &B {
+        &self.0 .1
+    }
+
+    /// Returns an adapter that displays the parts separated by a colon.
+    /// ```rust
+    /// # struct Document;
+    /// use typed_id::TypedId;
+    /// let id: TypedId<(u32, u32), Document> = (7, 42).into();
+    /// assert_eq!(id.display_composite().to_string(), "7:42");
+    /// ```
+    pub fn display_composite(&self) -> CompositeDisplay<'_, A, B>
+    where
+        A: fmt::Display,
+        B: fmt::Display,
+    {
+        CompositeDisplay(&self.0)
+    }
+
+    /// Parses a composite id from its parts separated by a colon. The string is split at the first
+    /// colon, so only the second part may contain colons.
+    /// ```rust
+    /// # struct Document;
+    /// use typed_id::TypedId;
+    /// let id = TypedId::<(u32, u32), Document>::parse_composite("7:42").unwrap();
+    /// assert_eq!((*id.part0(), *id.part1()), (7, 42));
+    /// ```
+    pub fn parse_composite(s: &str) -> Result<Self, ParseCompositeError<A::Err, B::Err>>
+    where
+        A: FromStr,
+        B: FromStr,
+    {
+        let (first, second) = s
+            .split_once(':')
+            .ok_or(ParseCompositeError::MissingSeparator)?;
+        let first = first.parse().map_err(ParseCompositeError::Part0)?;
+        let second = second.parse().map_err(ParseCompositeError::Part1)?;
+        Ok(TypedId::new((first, second)))
+    }
+}
+
+/// Displays a composite id as its parts separated by a colon. See
+/// `TypedId::display_composite`.
+#[derive(Debug, Clone, Copy)]
+pub struct CompositeDisplay<'a, A, B>(&'a (A, B));
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for CompositeDisplay<'_, A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.0 .0, self.0 .1)
+    }
+}
+
+/// The error returned by `TypedId::parse_composite`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCompositeError<E0, E1> {
+    /// The string does not contain a colon
+    MissingSeparator,
+    /// The first part could not be parsed
+    Part0(E0),
+    /// The second part could not be parsed
+    Part1(E1),
+}
+
+impl<E0: fmt::Display, E1: fmt::Display> fmt::Display for ParseCompositeError<E0, E1> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSeparator => f.write_str("composite id is missing the ':' separator"),
+            Self::Part0(err) => write!(f, "invalid first part of composite id: {err}"),
+            Self::Part1(err) => write!(f, "invalid second part of composite id: {err}"),
+        }
+    }
+}
+
+impl<E0, E1> std::error::Error for ParseCompositeError<E0, E1>
+where
+    E0: fmt::Debug + fmt::Display,
+    E1: fmt::Debug + fmt::Display,
+{
+}
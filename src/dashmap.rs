@@ -0,0 +1,78 @@
+//! This module adds helpers for using typed ids as the keys of a `DashMap`.
+
+use std::hash::Hash;
+
+use dashmap::{mapref::one::RefMut, DashMap};
+
+use crate::TypedId;
+
+/// A concurrent map keyed by `u32`-backed typed ids.
+///
+/// Since typed ids implement `Borrow` for their underlying id, the map can also be queried with
+/// the raw id.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{TypedDashMap, TypedId};
+///
+/// let names: TypedDashMap<Customer, &str> = TypedDashMap::new();
+/// names.insert(TypedId::new(1), "Jane");
+/// assert_eq!(*names.get(&1).unwrap(), "Jane");
+/// ```
+pub type TypedDashMap<T, V> = DashMap<TypedId<u32, T>, V>;
+
+/// An extension trait for `DashMap`s keyed by typed ids that works with raw underlying ids.
+pub trait TypedDashMapExt<I, T, V> {
+    /// Returns the value for the raw id, inserting the result of `f` if there is none.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::{TypedDashMap, TypedDashMapExt};
+    ///
+    /// let visits: TypedDashMap<Customer, u32> = TypedDashMap::new();
+    /// *visits.get_or_insert_raw_with(1, || 0) += 1;
+    /// *visits.get_or_insert_raw_with(1, || 0) += 1;
+    /// assert_eq!(*visits.get(&1).unwrap(), 2);
+    /// ```
+    fn get_or_insert_raw_with<F>(&self, raw: I, f: F) -> RefMut<'_, TypedId<I, T>, V>
+    where
+        F: FnOnce() -> V;
+}
+
+impl<I: Hash + Eq, T, V> TypedDashMapExt<I, T, V> for DashMap<TypedId<I, T>, V> {
+    fn get_or_insert_raw_with<F>(&self, raw: I, f: F) -> RefMut<'_, TypedId<I, T>, V>
+    where
+        F: FnOnce() -> V,
+    {
+        // The returned guard is mutable, so the shard's write lock is needed either way
+        self.entry(TypedId::new(raw)).or_insert_with(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, thread};
+
+    use crate::{TypedDashMap, TypedDashMapExt, TypedId};
+
+    struct Customer;
+
+    #[test]
+    fn concurrent_raw_and_typed_keys() {
+        let map: Arc<TypedDashMap<Customer, u32>> = Arc::default();
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let map = map.clone();
+                thread::spawn(move || {
+                    for raw in 0..100 {
+                        *map.get_or_insert_raw_with(raw, || 0) += 1;
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(map.len(), 100);
+        assert_eq!(*map.get(&7).unwrap(), 4);
+        assert_eq!(*map.get(&TypedId::new(7)).unwrap(), 4);
+    }
+}
@@ -0,0 +1,114 @@
+//! This module adds stable SHA-256 digests of typed ids, for content-addressed storage and other
+//! places where an id must hash identically on every platform and in every process.
+//!
+//! Unlike `Hash`, which feeds a randomly seeded `DefaultHasher` and depends on the platform's
+//! integer widths, the digest only depends on the bytes of the underlying id.
+
+use std::borrow::Cow;
+
+use sha2::{Digest, Sha256};
+
+use crate::TypedId;
+
+/// An underlying id type with a canonical, platform-independent byte representation.
+///
+/// Integers are written as big-endian, strings as UTF-8, and UUIDs as their 16 raw bytes. Note,
+/// `usize` and `isize` are not implemented as their width differs between platforms.
+pub trait DigestBytes {
+    /// Returns the bytes that are fed into the digest
+    fn digest_bytes(&self) -> Cow<'_, [u8]>;
+}
+
+macro_rules! impl_digest_bytes_int {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl DigestBytes for $int {
+                fn digest_bytes(&self) -> Cow<'_, [u8]> {
+                    Cow::Owned(self.to_be_bytes().to_vec())
+                }
+            }
+        )*
+    };
+}
+
+impl_digest_bytes_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl DigestBytes for str {
+    fn digest_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.as_bytes())
+    }
+}
+
+impl DigestBytes for String {
+    fn digest_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.as_bytes())
+    }
+}
+
+impl DigestBytes for &str {
+    fn digest_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.as_bytes())
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl DigestBytes for uuid::Uuid {
+    fn digest_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.as_bytes())
+    }
+}
+
+impl<I: DigestBytes, T> TypedId<I, T> {
+    /// Returns the SHA-256 digest of the underlying id. The marker type is not part of the digest,
+    /// so ids of different entities with the same underlying id have the same digest.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let id: TypedId<u32, Customer> = 42.into();
+    /// assert_eq!(id.stable_digest(), TypedId::<u32, Customer>::new(42).stable_digest());
+    /// ```
+    pub fn stable_digest(&self) -> [u8; 32] {
+        Sha256::digest(self.0.digest_bytes()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TypedId;
+
+    struct Customer;
+
+    fn hex(digest: [u8; 32]) -> String {
+        digest.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn known_digests() {
+        let id: TypedId<u32, Customer> = 42.into();
+        assert_eq!(
+            hex(id.stable_digest()),
+            "ae3c8b8d99a39542f78af83dbbb42c81cd94199ec1b5f60a0801063e95842570"
+        );
+
+        let id: TypedId<String, Customer> = "jane-doe".into();
+        assert_eq!(
+            hex(id.stable_digest()),
+            "2751e2c022b8c61ce7b3780cf77ecb9cc49a79032ca339ef02d080acbda894a0"
+        );
+        let id: TypedId<&str, Customer> = TypedId::new("jane-doe");
+        assert_eq!(
+            hex(id.stable_digest()),
+            "2751e2c022b8c61ce7b3780cf77ecb9cc49a79032ca339ef02d080acbda894a0"
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn known_uuid_digest() {
+        let id: TypedId<uuid::Uuid, Customer> = uuid::Uuid::nil().into();
+        assert_eq!(
+            hex(id.stable_digest()),
+            "374708fff7719dd5979ec875d56cd2286f6d3cf7ec317a3b25632aab28ec37bb"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! This module contains `EitherId`, an id for entities that can be addressed by either a numeric
+//! id or a string slug.
+
+use std::{fmt, hash::Hash};
+
+use crate::TypedId;
+
+/// An id that is either numeric or a string slug, but always belongs to the same entity type `T`.
+/// ```rust
+/// use typed_id::{EitherId, TypedId};
+/// struct Article;
+///
+/// let by_num: EitherId<Article> = TypedId::<u64, Article>::new(42).into();
+/// let by_slug: EitherId<Article> = TypedId::<String, Article>::new("hello-world".into()).into();
+/// assert_ne!(by_num, by_slug);
+/// ```
+///
+/// The marker still applies, so ids for different entities can't be mixed.
+/// ```compile_fail
+/// use typed_id::{EitherId, TypedId};
+/// struct Article;
+/// struct Author;
+///
+/// let id: EitherId<Article> = TypedId::<u64, Author>::new(42).into();
+/// ```
+pub enum EitherId<T> {
+    /// A numeric id
+    Numeric(TypedId<u64, T>),
+    /// A string id, often a slug
+    Slug(TypedId<String, T>),
+}
+
+impl<T> EitherId<T> {
+    /// Returns the numeric id, if this is one
+    pub fn numeric(&self) -> Option<TypedId<u64, T>> {
+        match self {
+            EitherId::Numeric(id) => Some(*id),
+            EitherId::Slug(_) => None,
+        }
+    }
+
+    /// Returns the slug, if this is one
+    pub fn slug(&self) -> Option<&TypedId<String, T>> {
+        match self {
+            EitherId::Numeric(_) => None,
+            EitherId::Slug(id) => Some(id),
+        }
+    }
+}
+
+impl<T> fmt::Debug for EitherId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EitherId::Numeric(id) => f.debug_tuple("Numeric").field(id).finish(),
+            EitherId::Slug(id) => f.debug_tuple("Slug").field(id).finish(),
+        }
+    }
+}
+
+impl<T> fmt::Display for EitherId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EitherId::Numeric(id) => id.fmt(f),
+            EitherId::Slug(id) => id.fmt(f),
+        }
+    }
+}
+
+impl<T> Clone for EitherId<T> {
+    fn clone(&self) -> Self {
+        match self {
+            EitherId::Numeric(id) => EitherId::Numeric(*id),
+            EitherId::Slug(id) => EitherId::Slug(id.clone()),
+        }
+    }
+}
+
+impl<T> Hash for EitherId<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            EitherId::Numeric(id) => {
+                state.write_u8(0);
+                id.hash(state)
+            }
+            EitherId::Slug(id) => {
+                state.write_u8(1);
+                id.hash(state)
+            }
+        }
+    }
+}
+
+impl<T> PartialEq for EitherId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (EitherId::Numeric(a), EitherId::Numeric(b)) => a == b,
+            (EitherId::Slug(a), EitherId::Slug(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<T> Eq for EitherId<T> {}
+
+impl<T> From<TypedId<u64, T>> for EitherId<T> {
+    fn from(other: TypedId<u64, T>) -> Self {
+        EitherId::Numeric(other)
+    }
+}
+
+impl<T> From<TypedId<String, T>> for EitherId<T> {
+    fn from(other: TypedId<String, T>) -> Self {
+        EitherId::Slug(other)
+    }
+}
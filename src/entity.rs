@@ -0,0 +1,170 @@
+//! This module contains the `Entity` trait, which links a type to its id type, the `Repository`
+//! trait, which gives stores of entities a consistent interface, and the `define_entity!` macro,
+//! which declares an entity and its id at once.
+
+/// A trait that links an entity type to its id type.
+pub trait Entity {
+    /// The id type of this entity, typically a `TypedId<_, Self>`
+    type Id;
+}
+
+/// A store of entities, keyed by their ids.
+///
+/// This is a skeleton for a data-access layer. Each store implements it once per entity that it
+/// holds, so code that only needs to get and insert entities can be generic over the store.
+/// ```rust
+/// use std::collections::HashMap;
+/// use typed_id::{define_entity, Repository};
+///
+/// define_entity! {
+///     #[derive(Clone)]
+///     pub struct Customer {
+///         pub name: String,
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct Store {
+///     customers: HashMap<CustomerId, Customer>,
+/// }
+///
+/// impl Repository<Customer> for Store {
+///     fn get(&self, id: CustomerId) -> Option<Customer> {
+///         self.customers.get(&id).cloned()
+///     }
+///
+///     fn insert(&mut self, customer: Customer) -> CustomerId {
+///         let id = CustomerId::new(self.customers.len() as u32);
+///         self.customers.insert(id, customer);
+///         id
+///     }
+/// }
+///
+/// let mut store = Store::default();
+/// let id = store.insert(Customer { name: "Jane".into() });
+/// assert_eq!(store.get(id).unwrap().name, "Jane");
+/// ```
+pub trait Repository<E: Entity> {
+    /// Returns the entity with the given id, if there is one
+    fn get(&self, id: E::Id) -> Option<E>;
+
+    /// Stores the entity, returning its newly assigned id
+    fn insert(&mut self, entity: E) -> E::Id;
+}
+
+/// A macro that declares an entity struct, its id alias, and the `Entity` impl that links them.
+/// By default, the ids are backed by a `u32`, but any underlying type can be given first.
+/// ```rust
+/// use typed_id::{define_entity, Entity, TypedId};
+/// // This is turned
+/// define_entity! {
+///     pub struct Customer {
+///         pub name: String,
+///     }
+/// }
+/// // into this
+/// // pub struct Customer { pub name: String }
+/// // pub type CustomerId = TypedId<u32, Customer>;
+/// // impl Entity for Customer { type Id = CustomerId; }
+///
+/// // And this
+/// define_entity! {
+///     u64,
+///     #[derive(Debug)]
+///     struct Order {
+///         customer: CustomerId,
+///     }
+/// }
+/// // into this
+/// // #[derive(Debug)]
+/// // struct Order { customer: CustomerId }
+/// // type OrderId = TypedId<u64, Order>;
+/// // impl Entity for Order { type Id = OrderId; }
+///
+/// let id: <Order as Entity>::Id = OrderId::new(42);
+///
+/// // Starting with `repository` also declares a repository trait for the entity
+/// define_entity! {
+///     repository,
+///     pub struct Invoice {
+///         pub total: u64,
+///     }
+/// }
+/// // which adds this
+/// // pub trait InvoiceRepository: Repository<Invoice> {}
+/// // impl<R: Repository<Invoice>> InvoiceRepository for R {}
+/// ```
+#[macro_export]
+macro_rules! define_entity {
+    (
+        repository,
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_meta:meta])* $field_vis:vis $field:ident : $field_type:ty),* $(,)?
+        }
+    ) => {
+        typed_id::define_entity! {
+            repository,
+            u32,
+            $(#[$meta])*
+            $vis struct $name {
+                $($(#[$field_meta])* $field_vis $field : $field_type),*
+            }
+        }
+    };
+    (
+        repository,
+        $id_type:ty,
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_meta:meta])* $field_vis:vis $field:ident : $field_type:ty),* $(,)?
+        }
+    ) => {
+        typed_id::define_entity! {
+            $id_type,
+            $(#[$meta])*
+            $vis struct $name {
+                $($(#[$field_meta])* $field_vis $field : $field_type),*
+            }
+        }
+
+        paste::paste! {
+            #[doc = concat!("A store of `", stringify!($name), "`s. See `typed_id::Repository`.")]
+            $vis trait [< $name Repository >]: typed_id::Repository<$name> {}
+
+            impl<R: typed_id::Repository<$name>> [< $name Repository >] for R {}
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_meta:meta])* $field_vis:vis $field:ident : $field_type:ty),* $(,)?
+        }
+    ) => {
+        typed_id::define_entity! {
+            u32,
+            $(#[$meta])*
+            $vis struct $name {
+                $($(#[$field_meta])* $field_vis $field : $field_type),*
+            }
+        }
+    };
+    (
+        $id_type:ty,
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_meta:meta])* $field_vis:vis $field:ident : $field_type:ty),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($(#[$field_meta])* $field_vis $field : $field_type),*
+        }
+
+        paste::paste! { $vis type [< $name Id >] = typed_id::TypedId<$id_type, $name>; }
+
+        impl typed_id::Entity for $name {
+            type Id = paste::paste! { [< $name Id >] };
+        }
+    };
+}
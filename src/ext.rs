@@ -0,0 +1,247 @@
+//! This module contains extension traits that add id-specific helpers to standard library types,
+//! along with a few free functions for collections of ids.
+
+use std::{
+    collections::HashSet,
+    fmt::{self, Write},
+    hash::Hash,
+    iter::FusedIterator,
+    marker::PhantomData,
+};
+
+use crate::TypedId;
+
+/// Sorting helpers for slices of typed ids.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{TypedId, TypedIdSliceExt};
+/// let mut ids: Vec<TypedId<u32, Customer>> = vec![3.into(), 1.into(), 2.into()];
+/// ids.sort_by_id_desc();
+/// assert_eq!(ids, [3.into(), 2.into(), 1.into()]);
+/// ```
+pub trait TypedIdSliceExt {
+    /// Sorts the ids in ascending order of their underlying ids. This sort is stable.
+    fn sort_by_id(&mut self);
+
+    /// Sorts the ids in descending order of their underlying ids. This sort is stable.
+    fn sort_by_id_desc(&mut self);
+
+    /// Sorts the ids in ascending order of their underlying ids. This sort is unstable.
+    fn sort_unstable_by_id(&mut self);
+}
+
+impl<I: Ord, T> TypedIdSliceExt for [TypedId<I, T>] {
+    fn sort_by_id(&mut self) {
+        self.sort_by(|a, b| a.0.cmp(&b.0))
+    }
+
+    fn sort_by_id_desc(&mut self) {
+        self.sort_by(|a, b| b.0.cmp(&a.0))
+    }
+
+    fn sort_unstable_by_id(&mut self) {
+        self.sort_unstable_by(|a, b| a.0.cmp(&b.0))
+    }
+}
+
+/// Helpers for `Vec`s of typed ids.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{TypedId, TypedIdVecExt};
+/// let mut ids: Vec<TypedId<u32, Customer>> = vec![2.into(), 1.into(), 2.into()];
+/// ids.dedup_preserve_order();
+/// assert_eq!(ids, [2.into(), 1.into()]);
+/// ```
+pub trait TypedIdVecExt {
+    /// Removes all but the first occurrence of each id, keeping the remaining ids in their
+    /// original order. Unlike `Vec::dedup`, the duplicates do not need to be consecutive.
+    fn dedup_preserve_order(&mut self);
+}
+
+impl<I: Hash + Eq, T> TypedIdVecExt for Vec<TypedId<I, T>> {
+    fn dedup_preserve_order(&mut self) {
+        // `retain` can't borrow the ids it is visiting, so first find which ones to keep
+        let mut seen = HashSet::with_capacity(self.len());
+        let keep: Vec<bool> = self.iter().map(|id| seen.insert(&id.0)).collect();
+        let mut keep = keep.into_iter();
+        self.retain(|_| keep.next().unwrap_or(true))
+    }
+}
+
+/// Converts an optional raw id into an optional typed id.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{OptionIdExt, TypedId};
+/// let id: Option<TypedId<u32, Customer>> = Some(42).typed();
+/// assert_eq!(id, Some(42.into()));
+/// ```
+pub trait OptionIdExt<I> {
+    /// Wraps the contained value, if any, in a `TypedId` with the marker type `T`
+    fn typed<T>(self) -> Option<TypedId<I, T>>;
+}
+
+impl<I> OptionIdExt<I> for Option<I> {
+    fn typed<T>(self) -> Option<TypedId<I, T>> {
+        self.map(TypedId::new)
+    }
+}
+
+/// Converts a fallible raw id into a fallible typed id.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{ResultIdExt, TypedId};
+/// let id: Result<TypedId<u32, Customer>, _> = "42".parse::<u32>().typed();
+/// assert_eq!(id, Ok(42.into()));
+/// ```
+pub trait ResultIdExt<I, E> {
+    /// Wraps the success value, if any, in a `TypedId` with the marker type `T`
+    fn typed<T>(self) -> Result<TypedId<I, T>, E>;
+}
+
+impl<I, E> ResultIdExt<I, E> for Result<I, E> {
+    fn typed<T>(self) -> Result<TypedId<I, T>, E> {
+        self.map(TypedId::new)
+    }
+}
+
+/// Lazily converts an iterator of raw ids into an iterator of typed ids.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{IdIteratorExt, TypedId};
+/// let mut ids = [1, 2, 3].into_iter().typed::<Customer>();
+/// assert_eq!(ids.next_back(), Some(TypedId::new(3)));
+/// assert_eq!(ids.len(), 2);
+/// ```
+pub trait IdIteratorExt: Iterator + Sized {
+    /// Wraps each item of the iterator in a `TypedId` with the marker type `T`
+    fn typed<T>(self) -> TypedIds<Self::Item, Self, T>;
+}
+
+impl<Iter: Iterator> IdIteratorExt for Iter {
+    fn typed<T>(self) -> TypedIds<Iter::Item, Iter, T> {
+        TypedIds {
+            iter: self,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Helpers for iterators of typed ids.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{TypedId, TypedIdIterExt};
+/// let ids: Vec<TypedId<u32, Customer>> = vec![1.into(), 2.into(), 3.into()];
+/// assert_eq!(ids.into_iter().join_display(" "), "1 2 3");
+/// ```
+pub trait TypedIdIterExt: Iterator + Sized {
+    /// Joins the display forms of the ids, separated by `sep`
+    fn join_display(self, sep: &str) -> String;
+}
+
+impl<Iter, I: fmt::Display, T> TypedIdIterExt for Iter
+where
+    Iter: Iterator<Item = TypedId<I, T>>,
+{
+    fn join_display(self, sep: &str) -> String {
+        let mut joined = String::new();
+        for (i, id) in self.enumerate() {
+            if i > 0 {
+                joined.push_str(sep);
+            }
+            // Writing into a `String` can not fail
+            let _ = write!(joined, "{id}");
+        }
+        joined
+    }
+}
+
+/// An iterator that wraps each item of another iterator in a `TypedId`. See
+/// `IdIteratorExt::typed`.
+pub struct TypedIds<I, Iter, T> {
+    iter: Iter,
+    marker: PhantomData<fn() -> TypedId<I, T>>,
+}
+
+impl<I, Iter: fmt::Debug, T> fmt::Debug for TypedIds<I, Iter, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedIds").field(&self.iter).finish()
+    }
+}
+
+impl<I, Iter: Clone, T> Clone for TypedIds<I, Iter, T> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<I, Iter: Iterator<Item = I>, T> Iterator for TypedIds<I, Iter, T> {
+    type Item = TypedId<I, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(TypedId::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, Iter: DoubleEndedIterator<Item = I>, T> DoubleEndedIterator for TypedIds<I, Iter, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(TypedId::new)
+    }
+}
+
+impl<I, Iter: ExactSizeIterator<Item = I>, T> ExactSizeIterator for TypedIds<I, Iter, T> {}
+
+impl<I, Iter: FusedIterator<Item = I>, T> FusedIterator for TypedIds<I, Iter, T> {}
+
+/// Pairs up two `Vec`s of ids, like a list of orders and the customers who placed them.
+///
+/// Unlike `Iterator::zip`, this does not silently drop the extra ids when the lengths differ.
+/// ```rust
+/// # struct Customer;
+/// # struct Order;
+/// use typed_id::{zip_ids, TypedId};
+/// let customers: Vec<TypedId<u32, Customer>> = vec![1.into(), 2.into()];
+/// let orders: Vec<TypedId<u64, Order>> = vec![10.into(), 20.into()];
+/// let pairs = zip_ids(customers, orders).unwrap();
+/// assert_eq!(pairs[1], (TypedId::new(2), TypedId::new(20)));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn zip_ids<I, A, J, B>(
+    left: Vec<TypedId<I, A>>,
+    right: Vec<TypedId<J, B>>,
+) -> Result<Vec<(TypedId<I, A>, TypedId<J, B>)>, ZipLengthError> {
+    if left.len() != right.len() {
+        return Err(ZipLengthError {
+            left: left.len(),
+            right: right.len(),
+        });
+    }
+    Ok(left.into_iter().zip(right).collect())
+}
+
+/// The error returned by `zip_ids` when the two `Vec`s have different lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZipLengthError {
+    /// The number of ids on the left
+    pub left: usize,
+    /// The number of ids on the right
+    pub right: usize,
+}
+
+impl fmt::Display for ZipLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "can not zip {} ids with {} ids, the lengths differ",
+            self.left, self.right
+        )
+    }
+}
+
+impl std::error::Error for ZipLengthError {}
@@ -0,0 +1,55 @@
+//! This implements flatbuffers' `Push` and `Follow` traits for all `TypedId` whose underlying type
+//! implements them. Like with serde, `TypedId`s are written and read as their underlying type, so
+//! the marker type never appears in the buffer.
+
+use flatbuffers::{Follow, Push, PushAlignment};
+
+use crate::TypedId;
+
+impl<I: Push, T> Push for TypedId<I, T> {
+    type Output = I::Output;
+
+    unsafe fn push(&self, dst: &mut [u8], written_len: usize) {
+        self.0.push(dst, written_len)
+    }
+
+    fn size() -> usize {
+        I::size()
+    }
+
+    fn alignment() -> PushAlignment {
+        I::alignment()
+    }
+}
+
+impl<'buf, I: Follow<'buf>, T> Follow<'buf> for TypedId<I, T> {
+    type Inner = TypedId<I::Inner, T>;
+
+    unsafe fn follow(buf: &'buf [u8], loc: usize) -> Self::Inner {
+        TypedId::new(I::follow(buf, loc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use flatbuffers::{FlatBufferBuilder, Follow};
+
+    use crate::TypedId;
+
+    type CustomerId = TypedId<u64, Customer>;
+
+    struct Customer;
+
+    #[test]
+    fn push_and_follow() {
+        let id: CustomerId = 42.into();
+
+        let mut builder = FlatBufferBuilder::new();
+        builder.push(id);
+        let data = builder.unfinished_data();
+        assert_eq!(data, 42u64.to_le_bytes());
+
+        let new_id = unsafe { CustomerId::follow(data, 0) };
+        assert_eq!(new_id, id);
+    }
+}
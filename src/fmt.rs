@@ -0,0 +1,50 @@
+//! This module contains formatting helpers for collections of ids.
+
+use std::fmt::{self, Display, Write};
+
+use crate::TypedId;
+
+/// Writes the ids into `out`, separated by `sep`, without any intermediate allocations.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{write_joined, TypedId};
+/// let ids: Vec<TypedId<u32, Customer>> = vec![1.into(), 2.into(), 3.into()];
+/// let mut out = String::from("ids=");
+/// write_joined(&ids, ",", &mut out).unwrap();
+/// assert_eq!(out, "ids=1,2,3");
+/// ```
+pub fn write_joined<I, T, W>(ids: &[TypedId<I, T>], sep: &str, out: &mut W) -> fmt::Result
+where
+    I: Display,
+    W: Write,
+{
+    let mut ids = ids.iter();
+    if let Some(first) = ids.next() {
+        write!(out, "{first}")?;
+        for id in ids {
+            out.write_str(sep)?;
+            write!(out, "{id}")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::write_joined;
+    use crate::TypedId;
+
+    struct Customer;
+
+    #[test]
+    fn joined_into_string() {
+        let ids: Vec<TypedId<u32, Customer>> = (1..=4).map(TypedId::new).collect();
+        let mut out = String::new();
+        write_joined(&ids, ", ", &mut out).unwrap();
+        assert_eq!(out, "1, 2, 3, 4");
+
+        let mut out = String::new();
+        write_joined(&ids[..0], ", ", &mut out).unwrap();
+        assert_eq!(out, "");
+    }
+}
@@ -0,0 +1,141 @@
+//! This module implements `arbitrary`'s `Arbitrary` for typed ids and contains helpers for seeding
+//! fuzz corpora and snapshot tests.
+//!
+//! A typed id is generated exactly like its underlying id, so fuzz targets can take typed ids (or
+//! structs containing them) directly.
+
+use arbitrary::{Arbitrary, Unstructured};
+
+use crate::TypedId;
+
+impl<'a, I: Arbitrary<'a>, T> Arbitrary<'a> for TypedId<I, T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        I::arbitrary(u).map(Self::new)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> arbitrary::Result<Self> {
+        I::arbitrary_take_rest(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        I::size_hint(depth)
+    }
+}
+
+/// An underlying id type with edge values that every corpus should cover.
+pub trait EdgeIds: Sized {
+    /// Returns the edge values, like zero and the extremes of an integer type
+    fn edge_ids() -> Vec<Self>;
+}
+
+macro_rules! impl_edge_ids {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl EdgeIds for $int {
+                fn edge_ids() -> Vec<Self> {
+                    let mut edges = vec![0, <$int>::MIN, <$int>::MAX, 1];
+                    edges.dedup();
+                    edges
+                }
+            }
+        )*
+    };
+}
+
+impl_edge_ids!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl EdgeIds for String {
+    fn edge_ids() -> Vec<Self> {
+        vec![String::new()]
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl EdgeIds for uuid::Uuid {
+    fn edge_ids() -> Vec<Self> {
+        vec![uuid::Uuid::nil(), uuid::Uuid::max()]
+    }
+}
+
+// The number of random bytes given to each generated id
+const BYTES_PER_ID: usize = 64;
+
+/// Returns `n` ids for seeding a fuzz corpus. The edge values of the underlying type come first,
+/// followed by ids generated from a fixed-seed pseudo-random stream, so the corpus is the same on
+/// every call.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{fuzz::id_corpus, TypedId};
+///
+/// let corpus = id_corpus::<u32, Customer>(10);
+/// assert_eq!(corpus.len(), 10);
+/// assert!(corpus.contains(&TypedId::new(u32::MAX)));
+/// assert_eq!(corpus, id_corpus::<u32, Customer>(10));
+/// ```
+pub fn id_corpus<I, T>(n: usize) -> Vec<TypedId<I, T>>
+where
+    I: EdgeIds + for<'a> Arbitrary<'a>,
+{
+    let mut corpus: Vec<_> = I::edge_ids()
+        .into_iter()
+        .take(n)
+        .map(TypedId::new)
+        .collect();
+    let mut state = 0x5eed_u64;
+    let mut bytes = [0; BYTES_PER_ID];
+    while corpus.len() < n {
+        for chunk in bytes.chunks_mut(8) {
+            chunk.copy_from_slice(&split_mix(&mut state).to_le_bytes());
+        }
+        // With enough bytes, generating an integer or string id never fails
+        if let Ok(id) = TypedId::arbitrary(&mut Unstructured::new(&bytes)) {
+            corpus.push(id);
+        }
+    }
+    corpus
+}
+
+/// The SplitMix64 generator, which is small, fast, and good enough for varied test data
+fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use super::id_corpus;
+    use crate::TypedId;
+
+    struct Customer;
+
+    type CustomerId = TypedId<i64, Customer>;
+
+    #[test]
+    fn arbitrary_like_inner() {
+        let bytes = [7; 16];
+        let id = CustomerId::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        let raw = i64::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(*id, raw);
+    }
+
+    #[test]
+    fn corpus_covers_edges() {
+        let corpus = id_corpus::<i64, Customer>(32);
+        assert_eq!(corpus.len(), 32);
+        assert!(corpus.contains(&CustomerId::new(i64::MIN)));
+        assert!(corpus.contains(&CustomerId::new(i64::MAX)));
+        assert!(corpus.contains(&CustomerId::new(0)));
+        assert!(corpus.iter().collect::<HashSet<_>>().len() > 16);
+        assert_eq!(corpus, id_corpus::<i64, Customer>(32));
+
+        assert_eq!(id_corpus::<i64, Customer>(2).len(), 2);
+        assert_eq!(id_corpus::<String, Customer>(8).len(), 8);
+    }
+}
@@ -0,0 +1,88 @@
+//! This module adds `heed` codecs for using typed ids as LMDB keys and values.
+
+use std::{borrow::Cow, fmt, marker::PhantomData};
+
+use heed::{
+    byteorder::BigEndian,
+    types::{U32, U64},
+    BoxedError, BytesDecode, BytesEncode,
+};
+
+use crate::TypedId;
+
+/// A `heed` codec for typed ids, which (de)serializes the underlying id with the codec `C`.
+/// ```rust
+/// # struct Customer;
+/// use heed::{BytesDecode, BytesEncode};
+/// use typed_id::{TypedId, U32IdCodec};
+///
+/// let id: TypedId<u32, Customer> = 42.into();
+/// let bytes = U32IdCodec::<Customer>::bytes_encode(&id).unwrap();
+/// assert_eq!(U32IdCodec::<Customer>::bytes_decode(&bytes).unwrap(), id);
+/// ```
+pub struct IdCodec<C, T>(PhantomData<fn() -> (C, T)>);
+
+/// A codec for `u32`-backed ids. This uses big-endian encoding, so the lexicographic order of the
+/// keys in LMDB matches the numeric order of the ids.
+pub type U32IdCodec<T> = IdCodec<U32<BigEndian>, T>;
+
+/// A codec for `u64`-backed ids. This uses big-endian encoding, so the lexicographic order of the
+/// keys in LMDB matches the numeric order of the ids.
+pub type U64IdCodec<T> = IdCodec<U64<BigEndian>, T>;
+
+impl<C, T> fmt::Debug for IdCodec<C, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("IdCodec")
+    }
+}
+
+impl<'a, C, T: 'a> BytesEncode<'a> for IdCodec<C, T>
+where
+    C: BytesEncode<'a>,
+    C::EItem: Sized,
+{
+    type EItem = TypedId<C::EItem, T>;
+
+    fn bytes_encode(item: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        C::bytes_encode(&item.0)
+    }
+}
+
+impl<'a, C: BytesDecode<'a>, T: 'a> BytesDecode<'a> for IdCodec<C, T> {
+    type DItem = TypedId<C::DItem, T>;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        C::bytes_decode(bytes).map(TypedId::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heed::{BytesDecode, BytesEncode};
+
+    use crate::{TypedId, U32IdCodec, U64IdCodec};
+
+    struct Customer;
+
+    #[test]
+    fn keys_sort_numerically() {
+        let ids = [65_536, 1, 256, 2, 255].map(TypedId::<u32, Customer>::new);
+        let mut keys: Vec<Vec<u8>> = ids
+            .iter()
+            .map(|id| U32IdCodec::bytes_encode(id).unwrap().into_owned())
+            .collect();
+        keys.sort();
+        let decoded: Vec<_> = keys
+            .iter()
+            .map(|key| U32IdCodec::<Customer>::bytes_decode(key).unwrap())
+            .collect();
+        assert_eq!(decoded, [1, 2, 255, 256, 65_536].map(TypedId::new));
+    }
+
+    #[test]
+    fn u64_round_trip() {
+        let id = TypedId::<u64, Customer>::new(u64::MAX - 1);
+        let bytes = U64IdCodec::bytes_encode(&id).unwrap();
+        assert_eq!(U64IdCodec::<Customer>::bytes_decode(&bytes).unwrap(), id);
+    }
+}
@@ -0,0 +1,58 @@
+//! This module adds aliases for using typed ids in insertion-ordered `IndexMap`s and `IndexSet`s.
+
+use indexmap::{IndexMap, IndexSet};
+
+use crate::TypedId;
+
+/// An insertion-ordered map keyed by `u32`-backed typed ids.
+///
+/// Since typed ids implement `Borrow` for their underlying id, the map can also be queried with
+/// the raw id, including by `get_index_of`.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{TypedId, TypedIndexMap};
+///
+/// let mut names: TypedIndexMap<Customer, &str> = TypedIndexMap::new();
+/// names.insert(TypedId::new(7), "Jane");
+/// names.insert(TypedId::new(3), "John");
+/// assert_eq!(names.get_index_of(&3), Some(1));
+/// assert_eq!(names[&7], "Jane");
+/// ```
+pub type TypedIndexMap<T, V> = IndexMap<TypedId<u32, T>, V>;
+
+/// An insertion-ordered set of `u32`-backed typed ids.
+///
+/// Like `TypedIndexMap`, the set can be queried with the raw id.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{TypedId, TypedIndexSet};
+///
+/// let mut ids: TypedIndexSet<Customer> = TypedIndexSet::new();
+/// ids.insert(TypedId::new(7));
+/// ids.insert(TypedId::new(3));
+/// assert_eq!(ids.get_index_of(&7), Some(0));
+/// assert!(ids.contains(&3));
+/// ```
+pub type TypedIndexSet<T> = IndexSet<TypedId<u32, T>>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{TypedId, TypedIndexMap, TypedIndexSet};
+
+    struct Customer;
+
+    #[test]
+    fn index_of_raw_id() {
+        let mut map: TypedIndexMap<Customer, u32> = TypedIndexMap::new();
+        let mut set: TypedIndexSet<Customer> = TypedIndexSet::new();
+        for raw in [5, 1, 9] {
+            map.insert(TypedId::new(raw), raw * 10);
+            set.insert(TypedId::new(raw));
+        }
+        assert_eq!(map.get_index_of(&1u32), Some(1));
+        assert_eq!(map.get_index_of(&TypedId::new(9)), Some(2));
+        assert_eq!(map.get_index_of(&4u32), None);
+        assert_eq!(set.get_index_of(&5u32), Some(0));
+        assert_eq!(set.get_index_of(&9u32), Some(2));
+    }
+}
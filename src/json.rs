@@ -0,0 +1,34 @@
+//! This implements conversions from `TypedId`s into `serde_json::Value`s. Like with serde, ids
+//! are converted to JSON as their underlying type.
+
+use serde_json::Value;
+
+use crate::TypedId;
+
+impl<I: Into<Value>, T> From<TypedId<I, T>> for Value {
+    fn from(other: TypedId<I, T>) -> Value {
+        other.0.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::TypedId;
+
+    type CustomerId = TypedId<u32, Customer>;
+
+    struct Customer;
+
+    #[test]
+    fn into_value() {
+        let id: CustomerId = 42.into();
+        assert_eq!(Value::from(id), json!(42));
+
+        let mut object = json!({ "name": "Jane" });
+        object["id"] = id.into();
+        assert_eq!(object, json!({ "name": "Jane", "id": 42 }));
+        assert_eq!(json!({ "id": id }), json!({ "id": 42 }));
+    }
+}
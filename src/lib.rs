@@ -71,10 +71,119 @@
 )]
 #![warn(rust_2018_idioms)]
 
-use std::{fmt, hash::Hash, marker::PhantomData, ops::Deref};
+use std::{
+    borrow::{Borrow, Cow},
+    hash::Hash,
+    marker::PhantomData,
+    ops::{Bound, Deref, RangeBounds},
+    rc::Rc,
+    str::FromStr,
+    sync::Arc,
+};
 
+mod any;
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+mod base62;
+#[cfg(feature = "bincode")]
+mod bincode;
+#[cfg(feature = "bitops")]
+mod bitops;
+#[cfg(feature = "cached-display")]
+mod cached_display;
+#[cfg(feature = "compact-str")]
+mod compact_str;
+mod composite;
+#[cfg(feature = "dashmap")]
+mod dashmap;
+#[cfg(feature = "digest")]
+mod digest;
+mod either;
+mod entity;
+mod ext;
+#[cfg(feature = "flatbuffers")]
+mod flatbuffers;
+mod fmt;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+#[cfg(feature = "heed")]
+mod heed;
+#[cfg(feature = "indexmap")]
+mod indexmap;
+#[cfg(feature = "json")]
+mod json;
+mod map;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod num;
+#[cfg(feature = "ordered-float")]
+mod ordered_float;
+mod packed;
+#[cfg(feature = "postcard")]
+mod postcard;
+mod prefix;
+#[cfg(feature = "redact")]
+mod redact;
+mod registry;
+#[cfg(feature = "salsa")]
+mod salsa;
 #[cfg(feature = "serde")]
 mod serde;
+mod shard;
+#[cfg(feature = "serde")]
+pub mod strict;
+#[cfg(feature = "tracing")]
+mod tracing;
+#[cfg(feature = "serde")]
+pub mod trim;
+#[cfg(feature = "ulid")]
+mod ulid;
+#[cfg(feature = "uuid")]
+mod uuid;
+mod vec;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+#[cfg(feature = "js-safe-number")]
+pub use crate::serde::js_safe_number;
+#[cfg(all(feature = "serde", feature = "uuid"))]
+pub use crate::serde::MsgPackUuid;
+#[cfg(feature = "serde")]
+pub use crate::serde::{
+    check_digit, csv_list, from_path, non_zero, null_as_zero, prefixed, sorted_set, ZeroPadded,
+};
+
+pub use any::{group_by_type, AnyId};
+pub use base62::ParseBase62Error;
+#[cfg(feature = "cached-display")]
+pub use cached_display::CachedDisplayId;
+pub use composite::{CompositeDisplay, ParseCompositeError};
+#[cfg(feature = "dashmap")]
+pub use dashmap::{TypedDashMap, TypedDashMapExt};
+#[cfg(feature = "digest")]
+pub use digest::DigestBytes;
+pub use either::EitherId;
+pub use entity::{Entity, Repository};
+pub use ext::{
+    zip_ids, IdIteratorExt, OptionIdExt, ResultIdExt, TypedIdIterExt, TypedIdSliceExt,
+    TypedIdVecExt, TypedIds, ZipLengthError,
+};
+pub use fmt::write_joined;
+#[cfg(feature = "heed")]
+pub use heed::{IdCodec, U32IdCodec, U64IdCodec};
+#[cfg(feature = "indexmap")]
+pub use indexmap::{TypedIndexMap, TypedIndexSet};
+pub use map::{collect_map_with_capacity, TypedBTreeExt, TypedMap};
+pub use num::{FixedWidth, LenError};
+#[cfg(feature = "ordered-float")]
+pub use ordered_float::OrderedFloatId;
+pub use packed::PackedId;
+pub use prefix::{IdPrefix, Prefixed};
+#[cfg(feature = "redact")]
+pub use redact::RedactedId;
+pub use registry::Registry;
+pub use vec::TypedVec;
 
 /// A macro to shorthand the creation of `TypeId` aliases.
 /// ```rust
@@ -90,9 +199,82 @@ mod serde;
 /// id_type!(pub, u32, Order);
 /// // into this
 /// // pub type OrderId = TypedId<u32, Order>;
+///
+/// // Adding a prefix also implements `IdPrefix` for the marker
+/// pub struct Invoice { id: InvoiceId };
+/// id_type!(pub, u32, Invoice, "inv_");
+/// // into this
+/// // pub type InvoiceId = TypedId<u32, Invoice>;
+/// // impl IdPrefix for Invoice { const PREFIX: &'static str = "inv_"; }
+///
+/// // Adding a default also implements `DefaultId` for the marker
+/// pub struct Shipment { id: ShipmentId };
+/// id_type!(pub, u32, Shipment, default = u32::MAX);
+/// // into this
+/// // pub type ShipmentId = TypedId<u32, Shipment>;
+/// // impl DefaultId<u32> for Shipment { fn default_id() -> u32 { u32::MAX } }
+/// assert_eq!(*ShipmentId::marker_default(), u32::MAX);
+///
+/// // Adding a range also implements `IdRange` for the marker
+/// pub struct Port { id: PortId };
+/// id_type!(pub, u16, Port, range = 1..1024);
+/// // into this
+/// // pub type PortId = TypedId<u16, Port>;
+/// // impl IdRange<u16> for Port { ... }
+/// assert!(PortId::try_new(80).is_ok());
+/// assert!(PortId::try_new(8080).is_err());
+/// ```
+///
+/// Note that the generated id is a type alias, so `Default::default()` still uses the underlying
+/// type's default. See `DefaultId` for details. For the same reason, the macro can not give a
+/// `Default` impl to ids whose underlying type has none, like `NonZeroU32`. Any expression can be
+/// used as the default, so `marker_default` covers those ids instead.
+/// ```rust
+/// use std::num::NonZeroU32;
+/// use typed_id::id_type;
+///
+/// pub struct Region { id: RegionId };
+/// id_type!(pub, NonZeroU32, Region, default = NonZeroU32::MIN);
+/// assert_eq!(*RegionId::marker_default(), NonZeroU32::MIN);
 /// ```
 #[macro_export]
 macro_rules! id_type {
+    ($id_type:ty, $name_type:ident, default = $default:expr) => {
+        typed_id::id_type!($id_type, $name_type);
+        impl typed_id::DefaultId<$id_type> for $name_type {
+            fn default_id() -> $id_type {
+                $default
+            }
+        }
+    };
+    ($where:vis, $id_type:ty, $name_type:ident, default = $default:expr) => {
+        typed_id::id_type!($where, $id_type, $name_type);
+        impl typed_id::DefaultId<$id_type> for $name_type {
+            fn default_id() -> $id_type {
+                $default
+            }
+        }
+    };
+    ($id_type:ty, $name_type:ident, range = $range:expr) => {
+        typed_id::id_type!($id_type, $name_type);
+        typed_id::__impl_id_range!($id_type, $name_type, $range);
+    };
+    ($where:vis, $id_type:ty, $name_type:ident, range = $range:expr) => {
+        typed_id::id_type!($where, $id_type, $name_type);
+        typed_id::__impl_id_range!($id_type, $name_type, $range);
+    };
+    ($id_type:ty, $name_type:ident, $prefix:literal) => {
+        typed_id::id_type!($id_type, $name_type);
+        impl typed_id::IdPrefix for $name_type {
+            const PREFIX: &'static str = $prefix;
+        }
+    };
+    ($where:vis, $id_type:ty, $name_type:ident, $prefix:literal) => {
+        typed_id::id_type!($where, $id_type, $name_type);
+        impl typed_id::IdPrefix for $name_type {
+            const PREFIX: &'static str = $prefix;
+        }
+    };
     ($id_type:ty, $name_type:ident) => {
         paste::paste! { type [< $name_type Id >] = typed_id::TypedId<$id_type, $name_type>; }
     };
@@ -101,15 +283,172 @@ macro_rules! id_type {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_id_range {
+    ($id_type:ty, $name_type:ident, $range:expr) => {
+        impl typed_id::IdRange<$id_type> for $name_type {
+            fn id_range() -> (::std::ops::Bound<$id_type>, ::std::ops::Bound<$id_type>) {
+                let range = $range;
+                (
+                    ::std::ops::RangeBounds::start_bound(&range).cloned(),
+                    ::std::ops::RangeBounds::end_bound(&range).cloned(),
+                )
+            }
+        }
+    };
+}
+
+/// A macro that fails to compile if the two given id types are the same type.
+///
+/// This guards against refactors that accidentally alias two markers, e.g. by turning one marker
+/// into a type alias of another. There is no runtime cost; the check is purely at compile time.
+/// ```rust
+/// use typed_id::{assert_distinct_ids, TypedId};
+/// # struct Customer;
+/// # struct Order;
+/// type CustomerId = TypedId<u32, Customer>;
+/// type OrderId = TypedId<u32, Order>;
+/// assert_distinct_ids!(CustomerId, OrderId);
+/// ```
+///
+/// Passing the same type twice, even through different aliases, fails to compile.
+/// ```compile_fail
+/// use typed_id::{assert_distinct_ids, TypedId};
+/// # struct Customer;
+/// type CustomerId = TypedId<u32, Customer>;
+/// type ClientId = TypedId<u32, Customer>;
+/// assert_distinct_ids!(CustomerId, ClientId);
+/// ```
+#[macro_export]
+macro_rules! assert_distinct_ids {
+    ($left:ty, $right:ty $(,)?) => {
+        const _: fn() = || {
+            // The two impls overlap, and so conflict, only when the types are the same
+            trait DistinctIds {}
+            impl DistinctIds for $left {}
+            impl DistinctIds for $right {}
+        };
+    };
+}
+
+/// A macro to define well-known ids, like the id of an "anonymous" customer, in one place.
+///
+/// The ids become associated constants of a generated `{Marker}WellKnown` type. The id alias must
+/// already exist, e.g. from `id_type!`.
+/// ```rust
+/// use typed_id::{id_type, well_known_ids};
+///
+/// pub struct Customer { id: CustomerId };
+/// id_type!(pub, u32, Customer);
+/// well_known_ids! { pub, Customer { ANONYMOUS = 0, SYSTEM = 1 } }
+/// // This generates
+/// // pub struct CustomerWellKnown;
+/// // impl CustomerWellKnown {
+/// //     pub const ANONYMOUS: CustomerId = CustomerId::new(0);
+/// //     pub const SYSTEM: CustomerId = CustomerId::new(1);
+/// // }
+///
+/// assert_eq!(*CustomerWellKnown::SYSTEM, 1);
+/// ```
+#[macro_export]
+macro_rules! well_known_ids {
+    ($name_type:ident { $($const_name:ident = $value:expr),* $(,)? }) => {
+        typed_id::well_known_ids!(pub(self), $name_type { $($const_name = $value),* });
+    };
+    ($where:vis, $name_type:ident { $($const_name:ident = $value:expr),* $(,)? }) => {
+        paste::paste! {
+            #[doc = "Well-known ids of `" $name_type "`"]
+            $where struct [< $name_type WellKnown >];
+
+            impl [< $name_type WellKnown >] {
+                $(
+                    pub const $const_name: [< $name_type Id >] = [< $name_type Id >]::new($value);
+                )*
+            }
+        }
+    };
+}
+
+/// Returns the unqualified name of the marker type, e.g. `Customer` for `my_crate::Customer`
+pub(crate) fn marker_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let base = name.split('<').next().unwrap_or(name);
+    base.rsplit("::").next().unwrap_or(base)
+}
+
 /// A generic type-checked wrapper around a generic identifier type
+///
+/// A typed id has the same layout as its underlying id, which allows collections of ids to be
+/// retyped in place. See `retype_vec`.
+#[repr(transparent)]
 pub struct TypedId<I, T>(pub I, PhantomData<T>);
 
 impl<I, T> TypedId<I, T> {
     /// Creates a new typed id with an underlying ID type of `I`
-    pub fn new(id: I) -> Self {
+    #[inline]
+    pub const fn new(id: I) -> Self {
         Self(id, PhantomData)
     }
 
+    /// Consumes the typed id, returning the underlying id. Unlike dereferencing, this can be used
+    /// in `const` contexts.
+    ///
+    /// The name doesn't collide with methods of the underlying id, like `NonZeroU32::get`, which
+    /// stay reachable through `Deref`.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// const ID: u32 = TypedId::<u32, Customer>::new(42).into_inner();
+    /// assert_eq!(ID, 42);
+    /// ```
+    #[inline]
+    pub const fn into_inner(self) -> I
+    where
+        I: Copy,
+    {
+        self.0
+    }
+
+    /// Returns a reference to the underlying id. Unlike dereferencing, this can be used in `const`
+    /// contexts.
+    #[inline]
+    pub const fn get_ref(&self) -> &I {
+        &self.0
+    }
+
+    /// Returns a reference to the underlying id, for use in sort comparators.
+    ///
+    /// Typed ids order exactly like their underlying ids, so they can be used as sort keys
+    /// directly, including in tuples like `sort_by_key(|r| (r.status, r.id))`. This is for
+    /// comparators that borrow, where the raw id reads more clearly.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let mut ids: Vec<TypedId<String, Customer>> = vec!["b".into(), "a".into()];
+    /// ids.sort_by(|a, b| a.as_sort_key().cmp(b.as_sort_key()));
+    /// assert_eq!(*ids[0], "a");
+    /// ```
+    #[inline]
+    pub const fn as_sort_key(&self) -> &I {
+        self.get_ref()
+    }
+
+    /// Replaces the underlying id, returning the previous one. The marker type is unchanged.
+    ///
+    /// This is not named `replace` so that it can't hide methods of the underlying id, like
+    /// `str::replace`, which stay reachable through `Deref`.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let mut id: TypedId<u32, Customer> = 42.into();
+    /// assert_eq!(id.replace_inner(7), 42);
+    /// assert_eq!(*id, 7);
+    /// ```
+    pub fn replace_inner(&mut self, new: I) -> I {
+        std::mem::replace(&mut self.0, new)
+    }
+
     /// The method explicitly converts between typed ids.
     /// ```rust
     /// # struct A;
@@ -128,30 +467,532 @@ impl<I, T> TypedId<I, T> {
     ///   }
     /// }
     /// ```
+    #[must_use]
     pub fn convert<B: From<I>>(self) -> B {
         B::from(self.0)
     }
+
+    /// Deliberately changes the marker type of this id while keeping the underlying id.
+    ///
+    /// Unlike `convert`, this is a plain move with no trait bounds and can only produce another
+    /// `TypedId`. It exists to make intentional marker changes (e.g. after renaming an entity)
+    /// explicit and easy to search for.
+    /// ```rust
+    /// # struct Account;
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let old: TypedId<u32, Account> = 42.into();
+    /// let new: TypedId<u32, Customer> = old.reinterpret();
+    /// assert_eq!(*new, 42);
+    /// ```
+    ///
+    /// The old id is moved, not changed in place, so discarding the result is a mistake.
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// # struct Account;
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let old: TypedId<u32, Account> = 42.into();
+    /// old.reinterpret::<Customer>();
+    /// ```
+    #[must_use]
+    pub fn reinterpret<U>(self) -> TypedId<I, U> {
+        TypedId(self.0, PhantomData)
+    }
+
+    /// Changes the marker type of every id in a `Vec`, like `reinterpret`, without reallocating
+    /// or touching the ids themselves.
+    /// ```rust
+    /// # struct Account;
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let old: Vec<TypedId<u32, Account>> = vec![1.into(), 2.into()];
+    /// let new: Vec<TypedId<u32, Customer>> = TypedId::retype_vec(old);
+    /// assert_eq!(new, [1, 2].map(TypedId::new));
+    /// ```
+    #[must_use]
+    pub fn retype_vec<U>(ids: Vec<Self>) -> Vec<TypedId<I, U>> {
+        let mut ids = std::mem::ManuallyDrop::new(ids);
+        let (ptr, len, cap) = (ids.as_mut_ptr(), ids.len(), ids.capacity());
+        // SAFETY: `TypedId` is `repr(transparent)` over `I`, so `TypedId<I, T>` and
+        // `TypedId<I, U>` have the same size and alignment. The allocation came from a `Vec` with
+        // this same length and capacity, and the original `Vec` is never dropped.
+        unsafe { Vec::from_raw_parts(ptr.cast(), len, cap) }
+    }
+
+    /// Changes the marker type of every id in a slice, like `reinterpret`, without copying.
+    /// ```rust
+    /// # struct Account;
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let old: [TypedId<u32, Account>; 2] = [1.into(), 2.into()];
+    /// let new: &[TypedId<u32, Customer>] = TypedId::retype_slice(&old);
+    /// assert_eq!(new, [1, 2].map(TypedId::new));
+    /// ```
+    #[must_use]
+    pub fn retype_slice<U>(ids: &[Self]) -> &[TypedId<I, U>] {
+        // SAFETY: `TypedId` is `repr(transparent)` over `I`, so both slices have the same layout.
+        // The returned slice borrows from `ids`, so the lifetime is unchanged.
+        unsafe { &*(ids as *const [Self] as *const [TypedId<I, U>]) }
+    }
+
+    /// Moves the underlying id into a `Box`.
+    ///
+    /// Note, `From<TypedId<I, T>>` can not be implemented for `Box<I>`. `Box` is a fundamental
+    /// type, so the orphan rules treat `Box<I>` like a bare `I`.
+    #[inline]
+    #[must_use]
+    pub fn into_box(self) -> Box<I> {
+        Box::new(self.0)
+    }
+
+    /// Copies this id out of a reference, like `Option::copied`.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let id: &TypedId<u32, Customer> = &42.into();
+    /// let owned: TypedId<u32, Customer> = id.copied();
+    /// assert_eq!(owned, *id);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn copied(&self) -> Self
+    where
+        I: Copy,
+    {
+        Self::new(self.0)
+    }
+
+    /// Clones this id. This is identical to `clone`, but makes the cost visible at the call site,
+    /// as cloning a non-`Copy` underlying id, like a `String`, may allocate.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let id: TypedId<String, Customer> = "jane".into();
+    /// assert_eq!(id.duplicate(), id);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn duplicate(&self) -> Self
+    where
+        I: Clone,
+    {
+        Self::new(self.0.clone())
+    }
+
+    /// Borrows the underlying id as a `Cow`, e.g. for use as a cache key without cloning. Use
+    /// `Cow::from` to move the id into an owned `Cow` instead.
+    /// ```rust
+    /// # struct Customer;
+    /// use std::borrow::Cow;
+    /// use typed_id::TypedId;
+    /// let id: TypedId<String, Customer> = "jane".into();
+    /// assert!(matches!(id.as_cow(), Cow::Borrowed(s) if s == "jane"));
+    /// assert!(matches!(Cow::from(id), Cow::Owned(s) if s == "jane"));
+    /// ```
+    #[inline]
+    pub fn as_cow(&self) -> Cow<'_, I>
+    where
+        I: Clone,
+    {
+        Cow::Borrowed(&self.0)
+    }
+
+    /// Creates a typed id from a shared underlying id, cloning it only if the `Arc` is shared.
+    /// ```rust
+    /// # struct Customer;
+    /// use std::sync::Arc;
+    /// use typed_id::TypedId;
+    /// let id = TypedId::<u32, Customer>::from_arc(Arc::new(42));
+    /// assert_eq!(Arc::<u32>::from(id), Arc::new(42));
+    /// ```
+    #[inline]
+    pub fn from_arc(id: Arc<I>) -> Self
+    where
+        I: Clone,
+    {
+        Self::new(Arc::unwrap_or_clone(id))
+    }
+
+    /// Compares this id to an id of the same marker type but a different underlying integer type.
+    /// Both ids are widened to `u64` before they are compared.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let old: TypedId<u32, Customer> = 42.into();
+    /// let new: TypedId<u64, Customer> = 42.into();
+    /// assert!(old.eq_widened(&new));
+    /// ```
+    pub fn eq_widened<J>(&self, other: &TypedId<J, T>) -> bool
+    where
+        I: Clone,
+        J: Clone,
+        u64: From<I> + From<J>,
+    {
+        u64::from(self.0.clone()) == u64::from(other.0.clone())
+    }
+
+    /// Returns the name of the marker type.
+    ///
+    /// This is the full path of the marker, e.g. `my_crate::Customer`, as given by
+    /// `std::any::type_name`. Like that function, the exact output is not guaranteed to be stable
+    /// across compiler versions, so it should be used for diagnostics and registries built at
+    /// runtime rather than persisted.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// assert!(TypedId::<u32, Customer>::type_name().ends_with("Customer"));
+    /// ```
+    #[inline]
+    pub fn type_name() -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    /// Compares the underlying id to a raw value.
+    ///
+    /// Typed ids deliberately can't be compared to raw ids with `==`, which would make it easy to
+    /// mix up ids by accident. This method makes such comparisons explicit and easy to search for.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let id: TypedId<u32, Customer> = 42.into();
+    /// assert!(id.inner_eq(&42));
+    /// ```
+    #[inline]
+    pub fn inner_eq(&self, other_inner: &I) -> bool
+    where
+        I: PartialEq,
+    {
+        self.0 == *other_inner
+    }
+
+    /// Orders the underlying id relative to a raw value. See `inner_eq`.
+    ///
+    /// This is also how to search a sorted slice of ids by a raw key.
+    /// ```rust
+    /// # struct Customer;
+    /// use std::cmp::Ordering;
+    /// use typed_id::TypedId;
+    /// let id: TypedId<u32, Customer> = 42.into();
+    /// assert_eq!(id.inner_cmp(&7), Ordering::Greater);
+    ///
+    /// let ids: Vec<TypedId<u32, Customer>> = vec![1.into(), 5.into(), 9.into()];
+    /// assert_eq!(ids.binary_search_by(|id| id.inner_cmp(&5)), Ok(1));
+    /// ```
+    #[inline]
+    pub fn inner_cmp(&self, other_inner: &I) -> std::cmp::Ordering
+    where
+        I: Ord,
+    {
+        self.0.cmp(other_inner)
+    }
+}
+
+/// Ids that belong jointly to two entities, like the rows of a join table, can use a tuple of
+/// both markers. These can be projected onto either entity when only one side is needed.
+/// ```rust
+/// # struct Customer;
+/// # struct Order;
+/// use typed_id::TypedId;
+/// let row: TypedId<u32, (Customer, Order)> = 42.into();
+/// let customer: TypedId<u32, Customer> = row.project_left();
+/// let order: TypedId<u32, Order> = row.project_right();
+/// assert_eq!(*customer, *order);
+/// ```
+impl<I, A, B> TypedId<I, (A, B)> {
+    /// Changes the marker to the first of the two markers, keeping the underlying id
+    #[must_use]
+    pub fn project_left(self) -> TypedId<I, A> {
+        self.reinterpret()
+    }
+
+    /// Changes the marker to the second of the two markers, keeping the underlying id
+    #[must_use]
+    pub fn project_right(self) -> TypedId<I, B> {
+        self.reinterpret()
+    }
+}
+
+/// An extension trait that allows any value to be turned into a typed id with the marker type
+/// specified inline via turbofish.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{IdExt, TypedId};
+/// fn lookup(id: TypedId<u32, Customer>) -> u32 {
+///     *id
+/// }
+///
+/// assert_eq!(lookup(42u32.as_id::<Customer>()), 42);
+/// ```
+pub trait IdExt: Sized {
+    /// Wraps this value in a `TypedId` with the marker type `T`
+    #[allow(clippy::wrong_self_convention)]
+    fn as_id<T>(self) -> TypedId<Self, T>;
+}
+
+impl<I> IdExt for I {
+    fn as_id<T>(self) -> TypedId<I, T> {
+        TypedId::new(self)
+    }
+}
+
+/// A trait for marker types that have a domain-specific default id, like a sentinel value.
+///
+/// Without specialization, the `Default` impl for `TypedId` can not depend on whether the marker
+/// implements this trait, so `Default` always uses the underlying type's default. Use
+/// `TypedId::marker_default` to get the marker's default instead.
+/// ```rust
+/// use typed_id::{DefaultId, TypedId};
+/// struct Order;
+///
+/// impl DefaultId<u32> for Order {
+///     fn default_id() -> u32 {
+///         u32::MAX
+///     }
+/// }
+///
+/// assert_eq!(*TypedId::<u32, Order>::marker_default(), u32::MAX);
+/// assert_eq!(*TypedId::<u32, Order>::default(), 0);
+/// ```
+pub trait DefaultId<I> {
+    /// Returns the default underlying id for this marker
+    fn default_id() -> I;
+}
+
+impl<I, T: DefaultId<I>> TypedId<I, T> {
+    /// Creates a typed id using the default id of the marker type
+    pub fn marker_default() -> Self {
+        Self::new(T::default_id())
+    }
+}
+
+/// A trait for marker types whose ids must fall in a range, like ports or table slots.
+///
+/// This is most easily implemented with the `range = ...` form of `id_type!`. Only `try_new`
+/// enforces the range. `new` is still unchecked, so it can be used on trusted paths, like ids read
+/// back from your own database.
+/// ```rust
+/// use std::ops::Bound;
+/// use typed_id::{IdRange, OutOfRange, TypedId};
+/// struct Slot;
+///
+/// impl IdRange<u8> for Slot {
+///     fn id_range() -> (Bound<u8>, Bound<u8>) {
+///         (Bound::Included(1), Bound::Excluded(16))
+///     }
+/// }
+///
+/// assert_eq!(*TypedId::<u8, Slot>::try_new(3).unwrap(), 3);
+/// assert_eq!(TypedId::<u8, Slot>::try_new(16), Err(OutOfRange { id: 16 }));
+/// ```
+pub trait IdRange<I> {
+    /// Returns the bounds that the underlying ids of this marker must fall within
+    fn id_range() -> (Bound<I>, Bound<I>);
+}
+
+impl<I: PartialOrd, T: IdRange<I>> TypedId<I, T> {
+    /// Creates a typed id, checking that the underlying id is within the marker's range
+    pub fn try_new(id: I) -> Result<Self, OutOfRange<I>> {
+        if T::id_range().contains(&id) {
+            Ok(Self::new(id))
+        } else {
+            Err(OutOfRange { id })
+        }
+    }
+}
+
+/// The error returned by `TypedId::try_new` when the underlying id is outside the marker's range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRange<I> {
+    /// The rejected id
+    pub id: I,
+}
+
+impl<I: std::fmt::Display> std::fmt::Display for OutOfRange<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "id {} is out of range", self.id)
+    }
+}
+
+impl<I: std::fmt::Debug + std::fmt::Display> std::error::Error for OutOfRange<I> {}
+
+/// A trait for underlying id types that can be viewed as a string, borrowing when possible.
+///
+/// String-like types borrow their contents, while other types (like integers) are formatted into
+/// an owned string.
+pub trait AsStrCow {
+    /// Returns the string form of this id, only allocating if it is not already a string
+    fn as_str_cow(&self) -> Cow<'_, str>;
+}
+
+impl AsStrCow for String {
+    #[inline]
+    fn as_str_cow(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl AsStrCow for &str {
+    #[inline]
+    fn as_str_cow(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl AsStrCow for Box<str> {
+    #[inline]
+    fn as_str_cow(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl AsStrCow for Cow<'_, str> {
+    #[inline]
+    fn as_str_cow(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+macro_rules! impl_as_str_cow_owned {
+    ($($inner:ty),* $(,)?) => {
+        $(
+            impl AsStrCow for $inner {
+                #[inline]
+                fn as_str_cow(&self) -> Cow<'_, str> {
+                    Cow::Owned(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_as_str_cow_owned!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, bool);
+
+impl<I: AsStrCow, T> TypedId<I, T> {
+    /// Returns the string form of the underlying id. This borrows for string-backed ids and only
+    /// allocates for other id types.
+    /// ```rust
+    /// # struct Customer;
+    /// use std::borrow::Cow;
+    /// use typed_id::TypedId;
+    /// let slug: TypedId<String, Customer> = "jane".into();
+    /// assert!(matches!(slug.as_str_cow(), Cow::Borrowed("jane")));
+    /// let num: TypedId<u32, Customer> = 42.into();
+    /// assert_eq!(num.as_str_cow(), "42");
+    /// ```
+    #[inline]
+    pub fn as_str_cow(&self) -> Cow<'_, str> {
+        self.0.as_str_cow()
+    }
+}
+
+impl<I: AsRef<str>, T> TypedId<I, T> {
+    /// Returns `true` if the underlying string id starts with the given prefix
+    #[inline]
+    pub fn has_id_prefix(&self, prefix: &str) -> bool {
+        self.0.as_ref().starts_with(prefix)
+    }
+
+    /// Returns a new id with the given prefix removed from the underlying string id, or `None` if
+    /// the id does not start with the prefix. The marker type is kept.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let id: TypedId<String, Customer> = "cust_42".into();
+    /// assert_eq!(id.strip_id_prefix("cust_").unwrap(), "42".into());
+    /// assert!(id.strip_id_prefix("ord_").is_none());
+    /// ```
+    #[must_use]
+    pub fn strip_id_prefix(&self, prefix: &str) -> Option<TypedId<String, T>> {
+        self.0
+            .as_ref()
+            .strip_prefix(prefix)
+            .map(|rest| TypedId::new(rest.to_owned()))
+    }
 }
 
 impl<I: Default, T> Default for TypedId<I, T> {
+    #[inline]
     fn default() -> Self {
         Self(Default::default(), Default::default())
     }
 }
 
-impl<I: fmt::Debug, T> fmt::Debug for TypedId<I, T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+// `debug_tuple` forwards the formatter's flags, so `{:#?}` also pretty-prints the underlying id
+impl<I: std::fmt::Debug, T> std::fmt::Debug for TypedId<I, T> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_tuple("TypedId").field(&self.0).finish()
     }
 }
 
-impl<I: fmt::Display, T> fmt::Display for TypedId<I, T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl<I: std::fmt::Display, T> std::fmt::Display for TypedId<I, T> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
     }
 }
 
+impl<I: FromStr, T> FromStr for TypedId<I, T> {
+    type Err = I::Err;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        I::from_str(s).map(Self::new)
+    }
+}
+
+// A blanket `impl<I: FromStr, T> TryFrom<&str> for TypedId<I, T>` conflicts with core's blanket
+// `TryFrom` impl (via `From<I>` when `I` is `&str`), so this is implemented for std's parsable
+// types instead. String ids get an infallible `From<&str>`, which provides `TryFrom` as well.
+macro_rules! impl_try_from_str {
+    ($($inner:ty),* $(,)?) => {
+        $(
+            impl<'a, T> TryFrom<&'a str> for TypedId<$inner, T> {
+                type Error = <$inner as FromStr>::Err;
+
+                #[inline]
+                fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                    s.parse()
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_str!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    bool,
+    char,
+    std::num::NonZeroU8,
+    std::num::NonZeroU16,
+    std::num::NonZeroU32,
+    std::num::NonZeroU64,
+    std::num::NonZeroU128,
+    std::num::NonZeroUsize,
+    std::num::NonZeroI8,
+    std::num::NonZeroI16,
+    std::num::NonZeroI32,
+    std::num::NonZeroI64,
+    std::num::NonZeroI128,
+    std::num::NonZeroIsize,
+);
+
 impl<I: Clone, T> Clone for TypedId<I, T> {
+    #[inline]
     fn clone(&self) -> Self {
         Self(self.0.clone(), PhantomData)
     }
@@ -160,12 +1001,16 @@ impl<I: Clone, T> Clone for TypedId<I, T> {
 impl<I: Copy, T> Copy for TypedId<I, T> {}
 
 impl<I: Hash, T> Hash for TypedId<I, T> {
+    #[inline]
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.0.hash(state)
     }
 }
 
+// The comparison forwarders are `#[inline(always)]` since they sit in the hottest loops (sorting,
+// searching, and filtering) and aren't always inlined in builds with a low `opt-level`.
 impl<I: PartialEq, T> PartialEq for TypedId<I, T> {
+    #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
         self.0.eq(&other.0)
     }
@@ -174,12 +1019,34 @@ impl<I: PartialEq, T> PartialEq for TypedId<I, T> {
 impl<I: Eq, T> Eq for TypedId<I, T> {}
 
 impl<I: PartialOrd, T> PartialOrd for TypedId<I, T> {
+    #[inline(always)]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         self.0.partial_cmp(&other.0)
     }
+
+    #[inline(always)]
+    fn lt(&self, other: &Self) -> bool {
+        self.0.lt(&other.0)
+    }
+
+    #[inline(always)]
+    fn le(&self, other: &Self) -> bool {
+        self.0.le(&other.0)
+    }
+
+    #[inline(always)]
+    fn gt(&self, other: &Self) -> bool {
+        self.0.gt(&other.0)
+    }
+
+    #[inline(always)]
+    fn ge(&self, other: &Self) -> bool {
+        self.0.ge(&other.0)
+    }
 }
 
 impl<I: Ord, T> Ord for TypedId<I, T> {
+    #[inline(always)]
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.0.cmp(&other.0)
     }
@@ -187,13 +1054,128 @@ impl<I: Ord, T> Ord for TypedId<I, T> {
 
 impl<I, T> Deref for TypedId<I, T> {
     type Target = I;
+    #[inline]
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
+impl<I, T> AsRef<TypedId<I, T>> for TypedId<I, T> {
+    #[inline]
+    fn as_ref(&self) -> &TypedId<I, T> {
+        self
+    }
+}
+
+/// Allows generic code that needs `&mut I` to accept a typed id.
+///
+/// Note, this gives up some of the opacity of `TypedId`, as any code with mutable access can
+/// change the underlying id arbitrarily. The marker type is unchanged, so it is up to the caller to
+/// keep the id valid for that marker. Prefer [`replace_inner`](TypedId::replace_inner) where the
+/// new id is known up front.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::TypedId;
+/// fn bump(id: &mut impl AsMut<u32>) {
+///     *id.as_mut() += 1;
+/// }
+///
+/// let mut id: TypedId<u32, Customer> = 42.into();
+/// bump(&mut id);
+/// assert_eq!(*id, 43);
+/// ```
+impl<I, T> AsMut<I> for TypedId<I, T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut I {
+        &mut self.0
+    }
+}
+
+/// Allows maps keyed by typed ids to be queried with the raw underlying id.
+impl<I, T> Borrow<I> for TypedId<I, T> {
+    #[inline]
+    fn borrow(&self) -> &I {
+        &self.0
+    }
+}
+
+/// Allows maps keyed by string ids to be queried with a `&str`, without allocating.
+/// ```rust
+/// # struct Customer;
+/// use std::collections::HashMap;
+/// use typed_id::TypedId;
+///
+/// let mut names: HashMap<TypedId<String, Customer>, &str> = HashMap::new();
+/// names.insert(String::from("jane-doe").into(), "Jane");
+/// assert_eq!(names.get("jane-doe"), Some(&"Jane"));
+/// ```
+impl<T> Borrow<str> for TypedId<String, T> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Allows maps keyed by byte ids to be queried with a `&[u8]`, without allocating.
+impl<T> Borrow<[u8]> for TypedId<Vec<u8>, T> {
+    #[inline]
+    fn borrow(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<T> From<&str> for TypedId<String, T> {
+    #[inline]
+    fn from(other: &str) -> TypedId<String, T> {
+        TypedId(other.to_owned(), PhantomData)
+    }
+}
+
 impl<I, T> From<I> for TypedId<I, T> {
+    #[inline]
     fn from(other: I) -> TypedId<I, T> {
         TypedId(other, PhantomData)
     }
 }
+
+impl<I: Clone, T> From<&TypedId<I, T>> for TypedId<I, T> {
+    #[inline]
+    fn from(other: &TypedId<I, T>) -> TypedId<I, T> {
+        other.clone()
+    }
+}
+
+impl<I, T> From<TypedId<I, T>> for (I,) {
+    #[inline]
+    fn from(other: TypedId<I, T>) -> (I,) {
+        (other.0,)
+    }
+}
+
+impl<I: Clone, T> From<&I> for TypedId<I, T> {
+    #[inline]
+    fn from(other: &I) -> TypedId<I, T> {
+        TypedId(other.clone(), PhantomData)
+    }
+}
+
+impl<I, T> From<TypedId<I, T>> for Rc<I> {
+    #[inline]
+    fn from(other: TypedId<I, T>) -> Rc<I> {
+        Rc::new(other.0)
+    }
+}
+
+impl<I, T> From<TypedId<I, T>> for Arc<I> {
+    #[inline]
+    fn from(other: TypedId<I, T>) -> Arc<I> {
+        Arc::new(other.0)
+    }
+}
+
+impl<I: Clone, T> From<TypedId<I, T>> for Cow<'_, I> {
+    #[inline]
+    fn from(other: TypedId<I, T>) -> Self {
+        Cow::Owned(other.0)
+    }
+}
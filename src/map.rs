@@ -0,0 +1,169 @@
+//! This module contains helpers for maps that are keyed by typed ids.
+
+use std::{
+    collections::{btree_map::Range, BTreeMap, HashMap},
+    fmt,
+    hash::Hash,
+    ops::{Deref, DerefMut, Index, IndexMut, RangeBounds},
+};
+
+use crate::{marker_name, TypedId};
+
+/// Collects `(id, value)` pairs into a `HashMap` that is pre-allocated to hold at least
+/// `capacity` entries. This avoids repeated reallocation during bulk loads when the number of
+/// entries is known up front, even if the iterator's size hint is not exact.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{collect_map_with_capacity, TypedId};
+/// let names = collect_map_with_capacity(
+///     [(TypedId::<u32, Customer>::new(1), "Jane")],
+///     100,
+/// );
+/// assert!(names.capacity() >= 100);
+/// ```
+pub fn collect_map_with_capacity<I, T, V, Iter>(
+    iter: Iter,
+    capacity: usize,
+) -> HashMap<TypedId<I, T>, V>
+where
+    I: Hash + Eq,
+    Iter: IntoIterator<Item = (TypedId<I, T>, V)>,
+{
+    let mut map = HashMap::with_capacity(capacity);
+    map.extend(iter);
+    map
+}
+
+/// Range queries by raw underlying ids for `BTreeMap`s keyed by typed ids.
+/// ```rust
+/// # struct Customer;
+/// use std::collections::BTreeMap;
+/// use typed_id::{TypedBTreeExt, TypedId};
+///
+/// let names: BTreeMap<TypedId<u32, Customer>, &str> =
+///     [(1.into(), "Jane"), (5.into(), "John"), (9.into(), "Joan")].into();
+/// let names: Vec<_> = names.range_by_inner(2..).map(|(_, name)| *name).collect();
+/// assert_eq!(names, ["John", "Joan"]);
+/// ```
+pub trait TypedBTreeExt<I, T, V> {
+    /// Returns an iterator over the entries whose underlying ids fall in the given range
+    fn range_by_inner<R: RangeBounds<I>>(&self, range: R) -> Range<'_, TypedId<I, T>, V>;
+}
+
+impl<I: Ord, T, V> TypedBTreeExt<I, T, V> for BTreeMap<TypedId<I, T>, V> {
+    fn range_by_inner<R: RangeBounds<I>>(&self, range: R) -> Range<'_, TypedId<I, T>, V> {
+        // Typed ids order exactly like their underlying ids, so the raw bounds can be used as is
+        self.range::<I, R>(range)
+    }
+}
+
+/// A `HashMap` keyed by `TypedId<I, T>` that can be indexed by id, for code that treats ids as
+/// handles to values that are always present. Dereferences to the underlying `HashMap`. The
+/// underlying id type `I` defaults to `u32`.
+///
+/// Like indexing a `Vec`, indexing with an id that is not in the map panics. Use `get` for ids
+/// that might be missing.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{TypedId, TypedMap};
+/// let mut names: TypedMap<Customer, &str> = TypedMap::new();
+/// let jane = TypedId::new(42);
+/// names.insert(jane, "Jane");
+/// names[jane] = "Jane Doe";
+/// assert_eq!(names[jane], "Jane Doe");
+///
+/// let mut emails: TypedMap<Customer, &str, String> = TypedMap::new();
+/// emails.insert(TypedId::new("jane".to_owned()), "jane@example.com");
+/// assert_eq!(emails[TypedId::new("jane".to_owned())], "jane@example.com");
+/// ```
+pub struct TypedMap<T, V, I = u32>(HashMap<TypedId<I, T>, V>);
+
+impl<T, V, I> TypedMap<T, V, I> {
+    /// Creates a new, empty map
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Creates a new, empty map with at least the specified capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Consumes this wrapper, returning the underlying `HashMap`
+    pub fn into_inner(self) -> HashMap<TypedId<I, T>, V> {
+        self.0
+    }
+}
+
+impl<T, V, I> Default for TypedMap<T, V, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V: fmt::Debug, I: fmt::Debug> fmt::Debug for TypedMap<T, V, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.0.iter()).finish()
+    }
+}
+
+impl<T, V: Clone, I: Clone> Clone for TypedMap<T, V, I> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T, V: PartialEq, I: Hash + Eq> PartialEq for TypedMap<T, V, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<T, V: Eq, I: Hash + Eq> Eq for TypedMap<T, V, I> {}
+
+impl<T, V, I> Deref for TypedMap<T, V, I> {
+    type Target = HashMap<TypedId<I, T>, V>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, V, I> DerefMut for TypedMap<T, V, I> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, V, I> From<HashMap<TypedId<I, T>, V>> for TypedMap<T, V, I> {
+    fn from(other: HashMap<TypedId<I, T>, V>) -> Self {
+        Self(other)
+    }
+}
+
+impl<T, V, I: Hash + Eq> FromIterator<(TypedId<I, T>, V)> for TypedMap<T, V, I> {
+    fn from_iter<Iter: IntoIterator<Item = (TypedId<I, T>, V)>>(iter: Iter) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T, V, I: Hash + Eq + fmt::Debug> Index<TypedId<I, T>> for TypedMap<T, V, I> {
+    type Output = V;
+
+    #[track_caller]
+    fn index(&self, id: TypedId<I, T>) -> &V {
+        match self.0.get(&id) {
+            Some(value) => value,
+            None => panic!("no {} with id {:?}", marker_name::<T>(), id.0),
+        }
+    }
+}
+
+impl<T, V, I: Hash + Eq + fmt::Debug> IndexMut<TypedId<I, T>> for TypedMap<T, V, I> {
+    #[track_caller]
+    fn index_mut(&mut self, id: TypedId<I, T>) -> &mut V {
+        match self.0.get_mut(&id) {
+            Some(value) => value,
+            None => panic!("no {} with id {:?}", marker_name::<T>(), id.0),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! This implements the conversion from typed ids into `metrics` label values. Label values are
+//! strings, so the underlying id is formatted with its `Display` impl.
+//! ```rust
+//! # struct Customer;
+//! use metrics::counter;
+//! use typed_id::TypedId;
+//!
+//! let id: TypedId<u32, Customer> = 42.into();
+//! counter!("orders_placed", "customer" => id).increment(1);
+//! ```
+//!
+//! Note, each distinct label value creates a new time series. Only label metrics by the ids of
+//! low-cardinality entities.
+
+use std::fmt::Display;
+
+use metrics::SharedString;
+
+use crate::TypedId;
+
+impl<I: Display, T> From<TypedId<I, T>> for SharedString {
+    fn from(id: TypedId<I, T>) -> Self {
+        SharedString::from_owned(id.0.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use metrics::Label;
+
+    use crate::TypedId;
+
+    struct Customer;
+
+    #[test]
+    fn label_from_id() {
+        let id: TypedId<u32, Customer> = 42.into();
+        let label = Label::new("customer", id);
+        assert_eq!(label.key(), "customer");
+        assert_eq!(label.value(), "42");
+    }
+}
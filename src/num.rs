@@ -0,0 +1,197 @@
+//! This module contains helpers for `TypedId`s whose underlying type is an integer.
+
+use std::{
+    fmt,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+};
+
+use crate::TypedId;
+
+macro_rules! impl_non_zero {
+    ($($prim:ty => $non_zero:ty),* $(,)?) => {
+        $(
+            impl<T> TypedId<$prim, T> {
+                #[doc = concat!("Converts this id into an id backed by a `", stringify!($non_zero), "`.")]
+                /// Returns `None` if the underlying id is zero.
+                pub fn try_into_nonzero(self) -> Option<TypedId<$non_zero, T>> {
+                    <$non_zero>::new(self.0).map(TypedId::new)
+                }
+            }
+
+            impl<T> TypedId<$non_zero, T> {
+                #[doc = concat!("Converts this id into an id backed by a `", stringify!($prim), "`.")]
+                pub fn into_primitive(self) -> TypedId<$prim, T> {
+                    TypedId::new(self.0.get())
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero!(
+    u8 => NonZeroU8,
+    u16 => NonZeroU16,
+    u32 => NonZeroU32,
+    u64 => NonZeroU64,
+    u128 => NonZeroU128,
+    usize => NonZeroUsize,
+    i8 => NonZeroI8,
+    i16 => NonZeroI16,
+    i32 => NonZeroI32,
+    i64 => NonZeroI64,
+    i128 => NonZeroI128,
+    isize => NonZeroIsize,
+);
+
+// Only widening conversions that std also provides are implemented, so these are always lossless.
+// Notably, `usize` can't be infallibly converted from a `u32` since `usize` might be 16 bits.
+macro_rules! impl_widening {
+    ($($from:ty => [$($into:ty),*]),* $(,)?) => {
+        $($(
+            impl<T> From<TypedId<$from, T>> for $into {
+                #[inline]
+                fn from(other: TypedId<$from, T>) -> $into {
+                    other.0.into()
+                }
+            }
+        )*)*
+    };
+}
+
+impl_widening!(
+    u8 => [u8, u16, u32, u64, u128, usize],
+    u16 => [u16, u32, u64, u128, usize],
+    u32 => [u32, u64, u128],
+    u64 => [u64, u128],
+    u128 => [u128],
+    usize => [usize],
+);
+
+macro_rules! impl_int_helpers {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl<T> TypedId<$int, T> {
+                /// Computes the raw distance from `other` to this id, returning `None` if the
+                /// subtraction would overflow. Unlike subtracting the underlying ids, this never
+                /// panics or wraps. The underlying id's own `checked_sub` stays reachable through
+                /// `Deref`.
+                #[inline]
+                pub fn checked_distance(self, other: Self) -> Option<$int> {
+                    self.0.checked_sub(other.0)
+                }
+
+                /// Converts the underlying id into a `u8`, saturating at `u8::MAX` (and at zero
+                /// for negative ids). Useful for mapping ids onto process exit codes.
+                #[inline]
+                pub fn as_u8_saturating(&self) -> u8 {
+                    u8::try_from(self.0).unwrap_or(if self.0 > 0 { u8::MAX } else { 0 })
+                }
+
+                /// Converts the underlying id into an index, saturating at `usize::MAX` (and at
+                /// zero for negative ids) instead of panicking. A saturated index is out of bounds
+                /// for any collection, so best-effort lookups miss rather than alias another id.
+                #[inline]
+                pub fn saturating_index(&self) -> usize {
+                    usize::try_from(self.0).unwrap_or(if self.0 > 0 { usize::MAX } else { 0 })
+                }
+            }
+        )*
+    };
+}
+
+impl_int_helpers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A fixed-width underlying id type that can be read from exactly as many bytes as it occupies.
+///
+/// This trait is sealed. It is implemented for the integer types, which are read as big-endian
+/// (network byte order), and for byte arrays, which are copied as-is.
+pub trait FixedWidth: sealed::Sealed + Sized {
+    /// The number of bytes this type occupies
+    const WIDTH: usize;
+
+    #[doc(hidden)]
+    fn from_exact(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_fixed_width {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $int {}
+
+            impl FixedWidth for $int {
+                const WIDTH: usize = std::mem::size_of::<$int>();
+
+                fn from_exact(bytes: &[u8]) -> Self {
+                    <$int>::from_be_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_width!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl<const N: usize> sealed::Sealed for [u8; N] {}
+
+impl<const N: usize> FixedWidth for [u8; N] {
+    const WIDTH: usize = N;
+
+    fn from_exact(bytes: &[u8]) -> Self {
+        bytes.try_into().unwrap()
+    }
+}
+
+impl<I: FixedWidth, T> TypedId<I, T> {
+    /// Reads an id from a slice that must be exactly as long as the underlying id type. Integers
+    /// are read as big-endian.
+    ///
+    /// Unlike reading a prefix of a larger buffer, this never silently ignores trailing bytes.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::{LenError, TypedId};
+    /// type CustomerId = TypedId<u32, Customer>;
+    ///
+    /// assert_eq!(CustomerId::from_bytes_exact(&[0, 0, 0, 42]), Ok(CustomerId::new(42)));
+    /// assert_eq!(
+    ///     CustomerId::from_bytes_exact(&[0, 42]),
+    ///     Err(LenError { expected: 4, got: 2 })
+    /// );
+    /// ```
+    pub fn from_bytes_exact(bytes: &[u8]) -> Result<Self, LenError> {
+        if bytes.len() != I::WIDTH {
+            return Err(LenError {
+                expected: I::WIDTH,
+                got: bytes.len(),
+            });
+        }
+        Ok(Self::new(I::from_exact(bytes)))
+    }
+}
+
+/// The error returned by `TypedId::from_bytes_exact` when the slice has the wrong length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LenError {
+    /// The number of bytes the underlying id occupies
+    pub expected: usize,
+    /// The number of bytes that were given
+    pub got: usize,
+}
+
+impl fmt::Display for LenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected exactly {} bytes for an id, got {}",
+            self.expected, self.got
+        )
+    }
+}
+
+impl std::error::Error for LenError {}
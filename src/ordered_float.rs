@@ -0,0 +1,69 @@
+//! This module adds support for ids backed by floats. `f64` is only `PartialOrd`, so a
+//! `TypedId<f64, T>` can't be used as the key of a `BTreeMap` or `HashMap`. `OrderedFloatId`
+//! is backed by an `OrderedFloat<f64>` instead, which is `Ord` and `Hash`.
+
+use ordered_float::OrderedFloat;
+
+use crate::TypedId;
+
+/// A typed id backed by an `f64` that is totally ordered and hashable.
+/// ```rust
+/// # struct Sample;
+/// use std::collections::BTreeSet;
+/// use typed_id::OrderedFloatId;
+///
+/// let ids: BTreeSet<OrderedFloatId<Sample>> = [2.5, 1.0].into_iter().map(Into::into).collect();
+/// assert_eq!(f64::from(*ids.first().unwrap()), 1.0);
+/// ```
+pub type OrderedFloatId<T> = TypedId<OrderedFloat<f64>, T>;
+
+impl<T> From<f64> for OrderedFloatId<T> {
+    fn from(other: f64) -> Self {
+        Self::new(OrderedFloat(other))
+    }
+}
+
+impl<T> From<OrderedFloatId<T>> for f64 {
+    fn from(other: OrderedFloatId<T>) -> f64 {
+        other.0 .0
+    }
+}
+
+impl<T> From<TypedId<f64, T>> for OrderedFloatId<T> {
+    fn from(other: TypedId<f64, T>) -> Self {
+        Self::new(OrderedFloat(other.0))
+    }
+}
+
+impl<T> From<OrderedFloatId<T>> for TypedId<f64, T> {
+    fn from(other: OrderedFloatId<T>) -> Self {
+        Self::new(other.0 .0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::OrderedFloatId;
+    use crate::TypedId;
+
+    struct Sample;
+
+    #[test]
+    fn float_ids_in_btree_set() {
+        let ids: BTreeSet<OrderedFloatId<Sample>> = [3.5, -1.0, 2.25, 3.5, f64::NAN]
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        assert_eq!(ids.len(), 4);
+
+        let raw: Vec<f64> = ids.iter().copied().map(f64::from).collect();
+        assert_eq!(raw[..3], [-1.0, 2.25, 3.5]);
+        assert!(raw[3].is_nan());
+
+        let plain: TypedId<f64, Sample> = (*ids.first().unwrap()).into();
+        assert_eq!(*plain, -1.0);
+        assert_eq!(OrderedFloatId::from(plain), *ids.first().unwrap());
+    }
+}
@@ -0,0 +1,146 @@
+//! This module contains `PackedId`, a wrapper for `u64` ids that pack a shard into their high bits.
+
+use std::{cmp::Ordering, fmt, hash::Hash, ops::Deref};
+
+use crate::TypedId;
+
+// The number of low bits of a packed id that hold the local id
+const LOCAL_BITS: u32 = 48;
+const LOCAL_MASK: u64 = (1 << LOCAL_BITS) - 1;
+
+/// A `u64` typed id that packs a 16-bit shard into the high bits and a 48-bit local id into the low
+/// bits.
+///
+/// The packing is opt-in: plain `TypedId<u64, T>`s are unaffected. The shard stored here is part
+/// of the id itself, unlike `TypedId::shard_of`, which hashes the id into a bucket.
+/// ```rust
+/// # struct Entity;
+/// use typed_id::{PackedId, TypedId};
+/// let id = PackedId::<Entity>::from_parts(3, 42);
+/// assert_eq!(id.shard(), 3);
+/// assert_eq!(id.local(), 42);
+/// assert_eq!(id.into_inner(), TypedId::new(0x0003_0000_0000_002A));
+/// ```
+pub struct PackedId<T>(TypedId<u64, T>);
+
+impl<T> PackedId<T> {
+    /// Packs a shard and a local id into one id.
+    ///
+    /// # Panics
+    /// Panics if the local id does not fit in 48 bits
+    #[inline]
+    pub const fn from_parts(shard: u16, local: u64) -> Self {
+        assert!(local <= LOCAL_MASK, "local id does not fit in 48 bits");
+        Self(TypedId::new((shard as u64) << LOCAL_BITS | local))
+    }
+
+    /// Returns the shard stored in the high 16 bits
+    #[inline]
+    pub const fn shard(&self) -> u16 {
+        (self.0 .0 >> LOCAL_BITS) as u16
+    }
+
+    /// Returns the local id stored in the low 48 bits
+    #[inline]
+    pub const fn local(&self) -> u64 {
+        self.0 .0 & LOCAL_MASK
+    }
+
+    /// Consumes this wrapper, returning the packed typed id
+    pub const fn into_inner(self) -> TypedId<u64, T> {
+        self.0
+    }
+}
+
+impl<T> fmt::Debug for PackedId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PackedId")
+            .field("shard", &self.shard())
+            .field("local", &self.local())
+            .finish()
+    }
+}
+
+impl<T> Clone for PackedId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for PackedId<T> {}
+
+impl<T> Hash for PackedId<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T> PartialEq for PackedId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<T> Eq for PackedId<T> {}
+
+impl<T> PartialOrd for PackedId<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for PackedId<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T> Deref for PackedId<T> {
+    type Target = TypedId<u64, T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+// Every `u64` is a valid packing, so this can't fail
+impl<T> From<TypedId<u64, T>> for PackedId<T> {
+    fn from(id: TypedId<u64, T>) -> Self {
+        Self(id)
+    }
+}
+
+impl<T> From<PackedId<T>> for TypedId<u64, T> {
+    fn from(id: PackedId<T>) -> Self {
+        id.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PackedId;
+    use crate::TypedId;
+
+    struct Entity;
+
+    #[test]
+    fn parts() {
+        let id = PackedId::<Entity>::from_parts(3, 42);
+        assert_eq!(id.shard(), 3);
+        assert_eq!(id.local(), 42);
+
+        let id = PackedId::<Entity>::from_parts(u16::MAX, (1 << 48) - 1);
+        assert_eq!(**id, u64::MAX);
+        assert_eq!(id.shard(), u16::MAX);
+        assert_eq!(id.local(), (1 << 48) - 1);
+
+        let id: PackedId<Entity> = TypedId::new(0x0007_0000_0000_0001).into();
+        assert_eq!((id.shard(), id.local()), (7, 1));
+        assert_eq!(format!("{id:?}"), "PackedId { shard: 7, local: 1 }");
+    }
+
+    #[test]
+    #[should_panic(expected = "local id does not fit in 48 bits")]
+    fn local_overflow() {
+        PackedId::<Entity>::from_parts(0, 1 << 48);
+    }
+}
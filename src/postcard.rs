@@ -0,0 +1,35 @@
+//! This implements postcard's `MaxSize` for all `TypedId` whose underlying type implements it.
+//! Since `TypedId`s are serialized as their underlying type, their max size is the same.
+
+use postcard::experimental::max_size::MaxSize;
+
+use crate::TypedId;
+
+impl<I: MaxSize, T> MaxSize for TypedId<I, T> {
+    const POSTCARD_MAX_SIZE: usize = I::POSTCARD_MAX_SIZE;
+}
+
+#[cfg(test)]
+mod tests {
+    use postcard::experimental::max_size::MaxSize;
+
+    use crate::TypedId;
+
+    type CustomerId = TypedId<u32, Customer>;
+
+    #[allow(dead_code)]
+    #[derive(MaxSize)]
+    struct Customer {
+        id: CustomerId,
+        orders: [TypedId<u64, Customer>; 4],
+    }
+
+    #[test]
+    fn max_size_matches_inner() {
+        assert_eq!(CustomerId::POSTCARD_MAX_SIZE, u32::POSTCARD_MAX_SIZE);
+        assert_eq!(
+            Customer::POSTCARD_MAX_SIZE,
+            u32::POSTCARD_MAX_SIZE + 4 * u64::POSTCARD_MAX_SIZE
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! This module contains support for ids that are displayed with a textual prefix, like `cus_42`.
+
+use std::fmt;
+
+use crate::TypedId;
+
+/// A trait for marker types whose ids have a textual prefix. This is most easily implemented via
+/// the `id_type!` macro.
+/// ```rust
+/// use typed_id::id_type;
+/// struct Customer;
+/// id_type!(u32, Customer, "cus_");
+///
+/// let id: CustomerId = 42.into();
+/// assert_eq!(format!("{}", id.prefixed()), "42");
+/// assert_eq!(format!("{:#}", id.prefixed()), "cus_42");
+/// ```
+pub trait IdPrefix {
+    /// The prefix that is prepended to the displayed id
+    const PREFIX: &'static str;
+}
+
+impl<I, T: IdPrefix> TypedId<I, T> {
+    /// Returns an adapter that displays the underlying id, with the marker's prefix prepended when
+    /// the alternate flag (`{:#}`) is used.
+    pub fn prefixed(&self) -> Prefixed<'_, I, T> {
+        Prefixed(self)
+    }
+}
+
+/// A display adapter for ids whose marker has a prefix. See `TypedId::prefixed`.
+pub struct Prefixed<'a, I, T>(&'a TypedId<I, T>);
+
+impl<I: fmt::Debug, T> fmt::Debug for Prefixed<'_, I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Prefixed").field(&self.0 .0).finish()
+    }
+}
+
+impl<I: fmt::Display, T: IdPrefix> fmt::Display for Prefixed<'_, I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}{}", T::PREFIX, self.0 .0)
+        } else {
+            write!(f, "{}", self.0 .0)
+        }
+    }
+}
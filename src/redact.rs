@@ -0,0 +1,99 @@
+//! This module contains `RedactedId`, a wrapper for ids that should never appear in logs.
+
+use std::{fmt, hash::Hash, ops::Deref};
+
+use crate::{marker_name, TypedId};
+
+/// A typed id whose `Debug` and `Display` implementations hide the underlying id. This is useful
+/// for ids that are sensitive, like session ids, so they aren't leaked through logging.
+///
+/// The real value is still accessible, via `expose` or dereferencing, and, when the `serde`
+/// feature is enabled, is (de)serialized as its underlying type.
+/// ```rust
+/// # struct Session;
+/// use typed_id::{RedactedId, TypedId};
+/// let id: RedactedId<u64, Session> = TypedId::new(42).into();
+/// assert_eq!(format!("{id:?}"), "SessionId(***)");
+/// assert_eq!(format!("{id}"), "SessionId(***)");
+/// assert_eq!(**id.expose(), 42);
+/// ```
+pub struct RedactedId<I, T>(TypedId<I, T>);
+
+impl<I, T> RedactedId<I, T> {
+    /// Creates a new redacted id with an underlying ID type of `I`
+    pub const fn new(id: I) -> Self {
+        Self(TypedId::new(id))
+    }
+
+    /// Returns the unredacted typed id
+    pub fn expose(&self) -> &TypedId<I, T> {
+        &self.0
+    }
+
+    /// Consumes this wrapper, returning the unredacted typed id
+    pub fn into_inner(self) -> TypedId<I, T> {
+        self.0
+    }
+}
+
+impl<I, T> fmt::Debug for RedactedId<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}Id(***)", marker_name::<T>())
+    }
+}
+
+impl<I, T> fmt::Display for RedactedId<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl<I: Clone, T> Clone for RedactedId<I, T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<I: Copy, T> Copy for RedactedId<I, T> {}
+
+impl<I: Hash, T> Hash for RedactedId<I, T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<I: PartialEq, T> PartialEq for RedactedId<I, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<I: Eq, T> Eq for RedactedId<I, T> {}
+
+impl<I, T> Deref for RedactedId<I, T> {
+    type Target = TypedId<I, T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<I, T> From<TypedId<I, T>> for RedactedId<I, T> {
+    fn from(other: TypedId<I, T>) -> Self {
+        Self(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedactedId;
+
+    struct Customer;
+
+    #[test]
+    fn redacted_output() {
+        let id: RedactedId<u32, Customer> = RedactedId::new(42);
+        assert_eq!(format!("{id:?}"), "CustomerId(***)");
+        assert_eq!(id.to_string(), "CustomerId(***)");
+        assert_eq!(**id, 42);
+    }
+}
@@ -0,0 +1,128 @@
+//! This module contains `Registry`, a container that hands out typed ids for its values.
+
+use std::{fmt, marker::PhantomData};
+
+use crate::TypedId;
+
+/// A container that assigns a `TypedId<u32, T>` to each inserted value and iterates in insertion
+/// order.
+///
+/// Ids are indices into the underlying `Vec`, so lookups are cheap. The marker type ensures that
+/// ids handed out by one registry can't be used with a registry of another entity.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::Registry;
+/// let mut names: Registry<Customer, &str> = Registry::new();
+/// let jane = names.insert("Jane");
+/// let john = names.insert("John");
+/// assert_eq!(names.get(jane), Some(&"Jane"));
+/// assert_eq!(names.iter().map(|(id, _)| id).collect::<Vec<_>>(), [jane, john]);
+/// ```
+///
+/// ```compile_fail
+/// # struct Customer;
+/// # struct Order;
+/// use typed_id::Registry;
+/// let mut customers: Registry<Customer, &str> = Registry::new();
+/// let orders: Registry<Order, u32> = Registry::new();
+/// let jane = customers.insert("Jane");
+/// orders.get(jane);
+/// ```
+pub struct Registry<T, V> {
+    values: Vec<V>,
+    marker: PhantomData<T>,
+}
+
+impl<T, V> Registry<T, V> {
+    /// Creates a new, empty registry
+    pub const fn new() -> Self {
+        Self {
+            values: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Inserts a value, returning its newly assigned id
+    ///
+    /// # Panics
+    /// Panics if the registry already holds `u32::MAX` values
+    pub fn insert(&mut self, value: V) -> TypedId<u32, T> {
+        let id = u32::try_from(self.values.len()).expect("registry has run out of ids");
+        self.values.push(value);
+        TypedId::new(id)
+    }
+
+    /// Returns the value with the given id
+    pub fn get(&self, id: TypedId<u32, T>) -> Option<&V> {
+        self.values.get(id.0 as usize)
+    }
+
+    /// Returns a mutable reference to the value with the given id
+    pub fn get_mut(&mut self, id: TypedId<u32, T>) -> Option<&mut V> {
+        self.values.get_mut(id.0 as usize)
+    }
+
+    /// Returns the number of values in the registry
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the registry holds no values
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterates over the ids and values in insertion order
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (TypedId<u32, T>, &V)> + ExactSizeIterator + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (TypedId::new(i as u32), value))
+    }
+}
+
+impl<T, V> Default for Registry<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V: fmt::Debug> fmt::Debug for Registry<T, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.iter().map(|(id, v)| (id.0, v)))
+            .finish()
+    }
+}
+
+impl<T, V: Clone> Clone for Registry<T, V> {
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Registry;
+
+    struct Customer;
+
+    #[test]
+    fn insertion_order() {
+        let mut registry: Registry<Customer, &str> = Registry::new();
+        let ids: Vec<_> = ["c", "a", "b"].map(|v| registry.insert(v)).into();
+        let values: Vec<_> = registry.iter().map(|(_, v)| *v).collect();
+        assert_eq!(values, ["c", "a", "b"]);
+        let iter_ids: Vec<_> = registry.iter().map(|(id, _)| id).collect();
+        assert_eq!(iter_ids, ids);
+
+        *registry.get_mut(ids[1]).unwrap() = "z";
+        assert_eq!(registry.get(ids[1]), Some(&"z"));
+        assert_eq!(registry.len(), 3);
+    }
+}
@@ -0,0 +1,47 @@
+//! This implements salsa's `InternKey` for typed ids, so they can be used as the keys of interned
+//! queries while keeping the entity type safety that raw `InternId`s lack.
+
+use salsa::{InternId, InternKey};
+
+use crate::TypedId;
+
+impl<T> InternKey for TypedId<InternId, T> {
+    fn from_intern_id(v: InternId) -> Self {
+        TypedId::new(v)
+    }
+
+    fn as_intern_id(&self) -> InternId {
+        self.0
+    }
+}
+
+/// Note, `InternId`s can not represent every `u32`. Converting an id above `InternId::MAX` panics.
+impl<T> InternKey for TypedId<u32, T> {
+    fn from_intern_id(v: InternId) -> Self {
+        TypedId::new(v.as_u32())
+    }
+
+    fn as_intern_id(&self) -> InternId {
+        InternId::from(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use salsa::{InternId, InternKey};
+
+    use crate::TypedId;
+
+    struct Symbol;
+
+    #[test]
+    fn intern_id_round_trip() {
+        let raw = InternId::from(42u32);
+        let id = TypedId::<InternId, Symbol>::from_intern_id(raw);
+        assert_eq!(id.as_intern_id(), raw);
+
+        let id = TypedId::<u32, Symbol>::from_intern_id(raw);
+        assert_eq!(*id, 42);
+        assert_eq!(id.as_intern_id(), raw);
+    }
+}
@@ -1,10 +1,33 @@
 //! This implements an opinionated version of the serde's (de)serializer for all `TypedId` whose
 //! underlying type is (de)serializable. In short, `TypedId`s are (de)serialized as thier
 //! underlying type. Otherwise, thier use as indices in maps is impractical.
+//!
+//! Because of this, typed ids can be used anywhere their underlying type can, including as the
+//! values of a `#[serde(flatten)]`-ed map. They also share their underlying type's limitations.
+//! For example, serde can not deserialize integer map keys inside of a flattened field, so neither
+//! a `u32` nor a `TypedId<u32, T>` can be used as the key of a flattened map.
+//! Flattening a struct whose fields are typed ids, like a composite key, works as it would with
+//! the underlying types, since the ids are buffered and read back as plain scalars.
+//!
+//! This also means that loosely-typed sources, like `config` and `envy`, work out of the box.
+//! These coerce strings into integers when asked for an integer, so an integer-backed id can be
+//! given as either `42` or `"42"`.
+//!
+//! For scalar ids, the `typed_id::strict` helper makes finding an object or a sequence where an id
+//! was expected produce an error that names the id, like "invalid type: map, expected CustomerId
+//! (a u32)".
+//!
+//! The `typed_id::trim` helper trims whitespace around string input before it is parsed, so an
+//! integer-backed id can also be given as `" 42 "`.
 
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{borrow::Cow, fmt, marker::PhantomData, str::FromStr};
 
-use crate::TypedId;
+use serde::{
+    de::{self, Visitor},
+    ser, Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{EitherId, TypedId};
 
 impl<'de, I: Deserialize<'de>, T> Deserialize<'de> for TypedId<I, T> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -13,6 +36,14 @@ impl<'de, I: Deserialize<'de>, T> Deserialize<'de> for TypedId<I, T> {
     {
         I::deserialize(deserializer).map(|id| id.into())
     }
+
+    // Lets ids with buffers, like `String`s, reuse their allocation when deserialized in place
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        I::deserialize_in_place(deserializer, &mut place.0)
+    }
 }
 
 impl<I: Serialize, T> Serialize for TypedId<I, T> {
@@ -24,9 +55,909 @@ impl<I: Serialize, T> Serialize for TypedId<I, T> {
     }
 }
 
+#[cfg(feature = "redact")]
+impl<'de, I: Deserialize<'de>, T> Deserialize<'de> for crate::RedactedId<I, T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        TypedId::deserialize(deserializer).map(Into::into)
+    }
+}
+
+#[cfg(feature = "redact")]
+impl<I: Serialize, T> Serialize for crate::RedactedId<I, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.expose().serialize(serializer)
+    }
+}
+
+impl<T> Serialize for EitherId<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            EitherId::Numeric(id) => id.serialize(serializer),
+            EitherId::Slug(id) => id.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for EitherId<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(EitherIdVisitor(PhantomData))
+    }
+}
+
+struct EitherIdVisitor<T>(PhantomData<T>);
+
+impl<T> Visitor<'_> for EitherIdVisitor<T> {
+    type Value = EitherId<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an unsigned integer or a string")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(EitherId::Numeric(v.into()))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        u64::try_from(v)
+            .map(|v| EitherId::Numeric(v.into()))
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(EitherId::Slug(v.to_owned().into()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(EitherId::Slug(v.into()))
+    }
+}
+
+/// A `deserialize_with` helper for typed ids backed by one of the `std::num::NonZero*` types.
+///
+/// The generic `Deserialize` impl forwards to the inner type, so a stored `0` is rejected with the
+/// inner type's generic error message. This module reports which kind of id was zero instead.
+/// ```rust
+/// use std::num::NonZeroU32;
+/// use serde::Deserialize;
+/// use typed_id::TypedId;
+///
+/// struct Customer;
+///
+/// #[derive(Deserialize)]
+/// struct Record {
+///     #[serde(deserialize_with = "typed_id::non_zero::deserialize")]
+///     id: TypedId<NonZeroU32, Customer>,
+/// }
+///
+/// let err = serde_json::from_str::<Record>(r#"{ "id": 0 }"#).err().unwrap();
+/// assert!(err.to_string().contains("CustomerId must be non-zero"));
+/// ```
+pub mod non_zero {
+    use std::num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    };
+
+    use serde::{de::Error, Deserialize, Deserializer};
+
+    use crate::{marker_name, TypedId};
+
+    /// The `NonZero*` types that can be deserialized through this module
+    pub trait NonZero: Sized {
+        /// The primitive integer type that this type wraps
+        type Primitive;
+
+        /// Returns `None` if the primitive is zero
+        fn new(inner: Self::Primitive) -> Option<Self>;
+    }
+
+    macro_rules! impl_non_zero {
+        ($($non_zero:ty => $prim:ty),* $(,)?) => {
+            $(
+                impl NonZero for $non_zero {
+                    type Primitive = $prim;
+
+                    fn new(inner: $prim) -> Option<Self> {
+                        <$non_zero>::new(inner)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_non_zero!(
+        NonZeroU8 => u8,
+        NonZeroU16 => u16,
+        NonZeroU32 => u32,
+        NonZeroU64 => u64,
+        NonZeroU128 => u128,
+        NonZeroUsize => usize,
+        NonZeroI8 => i8,
+        NonZeroI16 => i16,
+        NonZeroI32 => i32,
+        NonZeroI64 => i64,
+        NonZeroI128 => i128,
+        NonZeroIsize => isize,
+    );
+
+    /// Deserializes the primitive integer and rejects zero with an error that names the id type
+    pub fn deserialize<'de, D, I, T>(deserializer: D) -> Result<TypedId<I, T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        I: NonZero,
+        I::Primitive: Deserialize<'de>,
+    {
+        let inner = I::Primitive::deserialize(deserializer)?;
+        I::new(inner)
+            .map(TypedId::new)
+            .ok_or_else(|| D::Error::custom(format!("{}Id must be non-zero", marker_name::<T>())))
+    }
+}
+
+/// A serde `with` helper for `u64`-backed ids that are consumed by JavaScript, which can only
+/// represent integers up to 2^53 - 1 (`Number.MAX_SAFE_INTEGER`) exactly.
+///
+/// Ids up to that limit are serialized as numbers. Larger ids are serialized as strings so they
+/// aren't silently rounded. Both forms are accepted when deserializing.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::TypedId;
+///
+/// struct Customer;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "typed_id::js_safe_number")]
+///     id: TypedId<u64, Customer>,
+/// }
+///
+/// let json = serde_json::to_string(&Record { id: 42.into() }).unwrap();
+/// assert_eq!(json, r#"{"id":42}"#);
+/// let json = serde_json::to_string(&Record { id: u64::MAX.into() }).unwrap();
+/// assert_eq!(json, r#"{"id":"18446744073709551615"}"#);
+/// ```
+#[cfg(feature = "js-safe-number")]
+pub mod js_safe_number {
+    use std::fmt;
+
+    use serde::{
+        de::{self, Visitor},
+        Deserializer, Serializer,
+    };
+
+    use crate::TypedId;
+
+    /// The largest integer that JavaScript can represent exactly, 2^53 - 1
+    pub const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+    /// Serializes the id as a number if it is a safe integer and as a string otherwise
+    pub fn serialize<T, S>(id: &TypedId<u64, T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if id.0 > MAX_SAFE_INTEGER {
+            serializer.collect_str(&id.0)
+        } else {
+            serializer.serialize_u64(id.0)
+        }
+    }
+
+    /// Deserializes the id from either a number or a string
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<TypedId<u64, T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_any(JsSafeVisitor)
+            .map(TypedId::new)
+    }
+
+    struct JsSafeVisitor;
+
+    impl Visitor<'_> for JsSafeVisitor {
+        type Value = u64;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an unsigned integer or a string containing one")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            u64::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse()
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+    }
+}
+
+/// A serde `with` helper that (de)serializes a `Vec` of typed ids as a single comma-separated
+/// string, e.g. `"1,2,3"`. An empty string is an empty `Vec`.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::TypedId;
+///
+/// struct Customer;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Query {
+///     #[serde(with = "typed_id::csv_list")]
+///     ids: Vec<TypedId<u32, Customer>>,
+/// }
+///
+/// let json = serde_json::to_string(&Query { ids: vec![1.into(), 2.into()] }).unwrap();
+/// assert_eq!(json, r#"{"ids":"1,2"}"#);
+/// ```
+pub mod csv_list {
+    use std::{borrow::Cow, fmt, str::FromStr};
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use crate::TypedId;
+
+    /// Serializes the ids by joining their `Display` output with commas
+    pub fn serialize<I, T, S>(ids: &[TypedId<I, T>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: fmt::Display,
+        S: Serializer,
+    {
+        let mut joined = String::new();
+        for (i, id) in ids.iter().enumerate() {
+            if i != 0 {
+                joined.push(',');
+            }
+            joined.push_str(&id.to_string());
+        }
+        serializer.serialize_str(&joined)
+    }
+
+    /// Deserializes the ids by splitting on commas and parsing each id
+    pub fn deserialize<'de, I, T, D>(deserializer: D) -> Result<Vec<TypedId<I, T>>, D::Error>
+    where
+        I: FromStr,
+        I::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        let joined = Cow::<'de, str>::deserialize(deserializer)?;
+        if joined.is_empty() {
+            return Ok(Vec::new());
+        }
+        joined
+            .split(',')
+            .map(|id| id.parse().map_err(de::Error::custom))
+            .collect()
+    }
+}
+
+/// A serde `with` helper for ids whose payloads use `null` to mean "no id yet".
+///
+/// A `null` is deserialized as the underlying type's default, like `0`, and that sentinel is
+/// serialized back as `null`. Note, this is lossy: a real id that equals the default can not be
+/// distinguished from a missing one. Use an `Option` if that distinction matters.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::TypedId;
+///
+/// struct Customer;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "typed_id::null_as_zero")]
+///     id: TypedId<u32, Customer>,
+/// }
+///
+/// let record: Record = serde_json::from_str(r#"{"id":null}"#).unwrap();
+/// assert_eq!(*record.id, 0);
+/// assert_eq!(serde_json::to_string(&record).unwrap(), r#"{"id":null}"#);
+/// ```
+pub mod null_as_zero {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::TypedId;
+
+    /// Serializes the id as `null` if it is the default id and as the underlying id otherwise
+    pub fn serialize<I, T, S>(id: &TypedId<I, T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: Default + PartialEq + Serialize,
+        S: Serializer,
+    {
+        if id.0 == I::default() {
+            serializer.serialize_none()
+        } else {
+            serializer.serialize_some(&id.0)
+        }
+    }
+
+    /// Deserializes the id, treating `null` as the default id
+    pub fn deserialize<'de, I, T, D>(deserializer: D) -> Result<TypedId<I, T>, D::Error>
+    where
+        I: Default + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Option::<I>::deserialize(deserializer).map(|id| TypedId::new(id.unwrap_or_default()))
+    }
+}
+
+/// A serde `with` helper that serializes a `HashSet` of typed ids as a sorted array, so equal sets
+/// always produce identical output, e.g. for golden tests or cache keys.
+/// ```rust
+/// use std::collections::HashSet;
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::TypedId;
+///
+/// struct Customer;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Segment {
+///     #[serde(with = "typed_id::sorted_set")]
+///     members: HashSet<TypedId<u32, Customer>>,
+/// }
+///
+/// let members = [3, 1, 2].map(TypedId::new).into();
+/// let json = serde_json::to_string(&Segment { members }).unwrap();
+/// assert_eq!(json, r#"{"members":[1,2,3]}"#);
+/// ```
+pub mod sorted_set {
+    use std::{
+        collections::HashSet,
+        hash::{BuildHasher, Hash},
+    };
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::TypedId;
+
+    /// Serializes the ids as an array, sorted by the underlying ids
+    pub fn serialize<I, T, H, S>(
+        ids: &HashSet<TypedId<I, T>, H>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        I: Ord + Serialize,
+        S: Serializer,
+    {
+        let mut sorted: Vec<_> = ids.iter().collect();
+        sorted.sort_unstable();
+        serializer.collect_seq(sorted)
+    }
+
+    /// Deserializes the ids from an array in any order
+    pub fn deserialize<'de, I, T, H, D>(
+        deserializer: D,
+    ) -> Result<HashSet<TypedId<I, T>, H>, D::Error>
+    where
+        I: Hash + Eq + Deserialize<'de>,
+        H: BuildHasher + Default,
+        D: Deserializer<'de>,
+    {
+        HashSet::deserialize(deserializer)
+    }
+}
+
+/// A serde `with` helper for user-facing integer ids, like invoice numbers, that (de)serializes
+/// them as strings with a trailing Luhn check digit.
+///
+/// The check digit catches most transcription errors, like a single mistyped digit or two swapped
+/// adjacent digits. Strings with a wrong check digit are rejected when deserializing.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::TypedId;
+///
+/// struct Invoice;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "typed_id::check_digit")]
+///     id: TypedId<u64, Invoice>,
+/// }
+///
+/// let json = serde_json::to_string(&Record { id: 7992739871.into() }).unwrap();
+/// assert_eq!(json, r#"{"id":"79927398713"}"#);
+/// assert!(serde_json::from_str::<Record>(r#"{"id":"79927398714"}"#).is_err());
+/// ```
+pub mod check_digit {
+    use std::{borrow::Cow, fmt, str::FromStr};
+
+    use serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+    use crate::TypedId;
+
+    /// Returns the Luhn sum of the digits, doubling every other digit starting from the last one
+    /// if `double_last` is set
+    fn luhn_sum(digits: &[u8], double_last: bool) -> u32 {
+        digits
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, digit)| {
+                let digit = u32::from(digit - b'0');
+                if (i % 2 == 0) == double_last {
+                    let doubled = digit * 2;
+                    doubled / 10 + doubled % 10
+                } else {
+                    digit
+                }
+            })
+            .sum()
+    }
+
+    /// Serializes the id as its digits followed by the check digit
+    pub fn serialize<I, T, S>(id: &TypedId<I, T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: fmt::Display,
+        S: Serializer,
+    {
+        let mut digits = id.0.to_string();
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ser::Error::custom(format!(
+                "id {digits} can not have a check digit, it must be a non-negative integer"
+            )));
+        }
+        let check = (10 - luhn_sum(digits.as_bytes(), true) % 10) % 10;
+        digits.push(char::from(b'0' + check as u8));
+        serializer.serialize_str(&digits)
+    }
+
+    /// Deserializes the id, validating and then removing the check digit
+    pub fn deserialize<'de, I, T, D>(deserializer: D) -> Result<TypedId<I, T>, D::Error>
+    where
+        I: FromStr,
+        I::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        let digits = Cow::<'de, str>::deserialize(deserializer)?;
+        if digits.len() < 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Str(&digits),
+                &"a string of digits ending in a check digit",
+            ));
+        }
+        if !luhn_sum(digits.as_bytes(), false).is_multiple_of(10) {
+            return Err(de::Error::custom(format!(
+                "id {digits} has an invalid check digit"
+            )));
+        }
+        digits[..digits.len() - 1]
+            .parse()
+            .map(TypedId::new)
+            .map_err(de::Error::custom)
+    }
+}
+
+/// A serde `with` helper that (de)serializes ids with a type-discriminator prefix, e.g.
+/// `"customer:42"`, so the entity type can be recovered from the string alone.
+///
+/// The prefix is the unqualified name of the marker type in snake case, so a `LineItem` id is
+/// written as `"line_item:7"`. Deserializing a string with a different prefix is an error.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::TypedId;
+///
+/// struct Customer;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "typed_id::prefixed")]
+///     subject: TypedId<u32, Customer>,
+/// }
+///
+/// let json = serde_json::to_string(&Event { subject: 42.into() }).unwrap();
+/// assert_eq!(json, r#"{"subject":"customer:42"}"#);
+/// assert!(serde_json::from_str::<Event>(r#"{"subject":"order:42"}"#).is_err());
+/// ```
+pub mod prefixed {
+    use std::{borrow::Cow, fmt, str::FromStr};
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use crate::{marker_name, TypedId};
+
+    /// Returns the discriminator for the marker type, its unqualified name in snake case
+    fn discriminator<T>() -> String {
+        let mut prefix = String::new();
+        for (i, c) in marker_name::<T>().char_indices() {
+            if c.is_uppercase() && i != 0 {
+                prefix.push('_');
+            }
+            prefix.extend(c.to_lowercase());
+        }
+        prefix
+    }
+
+    /// Serializes the id as the discriminator, a colon, and then the underlying id
+    pub fn serialize<I, T, S>(id: &TypedId<I, T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(&format_args!("{}:{}", discriminator::<T>(), id.0))
+    }
+
+    /// Deserializes the id, checking that its discriminator matches the marker type
+    pub fn deserialize<'de, I, T, D>(deserializer: D) -> Result<TypedId<I, T>, D::Error>
+    where
+        I: FromStr,
+        I::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        let s = Cow::<'de, str>::deserialize(deserializer)?;
+        let expected = discriminator::<T>();
+        let Some((prefix, id)) = s.split_once(':') else {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Str(&s),
+                &format!("an id of the form \"{expected}:<id>\"").as_str(),
+            ));
+        };
+        if prefix != expected {
+            return Err(de::Error::custom(format!(
+                "expected an id prefixed with {expected:?}, found {prefix:?}"
+            )));
+        }
+        id.parse().map(TypedId::new).map_err(de::Error::custom)
+    }
+}
+
+/// Helpers for ids that are nested inside of a payload, like `{"data": {"id": 42}}`, without
+/// defining a wrapper struct for each level.
+///
+/// Serde `with` modules can't take arguments and const string generics are not stable, so
+/// `typed_id::from_path!` generates a `with` module for a dot-separated path instead.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::TypedId;
+///
+/// struct Customer;
+///
+/// typed_id::from_path!(data_id, "data.id");
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Envelope {
+///     #[serde(with = "data_id")]
+///     payload: TypedId<u32, Customer>,
+/// }
+///
+/// let envelope: Envelope = serde_json::from_str(r#"{"payload":{"data":{"id":42}}}"#).unwrap();
+/// assert_eq!(envelope.payload, TypedId::new(42));
+/// let json = serde_json::to_string(&envelope).unwrap();
+/// assert_eq!(json, r#"{"payload":{"data":{"id":42}}}"#);
+/// ```
+pub mod from_path {
+    use std::fmt;
+
+    use serde::{
+        de::{self, DeserializeSeed, IgnoredAny, MapAccess, Visitor},
+        ser::SerializeMap,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use crate::TypedId;
+
+    /// Nests the underlying id in one single-entry map per path segment
+    struct Nested<'a, I> {
+        path: &'a str,
+        id: &'a I,
+    }
+
+    impl<I: Serialize> Serialize for Nested<'_, I> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self.path.split_once('.') {
+                Some((key, path)) => {
+                    let mut map = serializer.serialize_map(Some(1))?;
+                    map.serialize_entry(key, &Nested { path, id: self.id })?;
+                    map.end()
+                }
+                None if self.path.is_empty() => self.id.serialize(serializer),
+                None => {
+                    let mut map = serializer.serialize_map(Some(1))?;
+                    map.serialize_entry(self.path, self.id)?;
+                    map.end()
+                }
+            }
+        }
+    }
+
+    /// Walks down the remaining path, skipping every other field along the way
+    struct PathSeed<'a, I> {
+        path: &'a str,
+        marker: std::marker::PhantomData<I>,
+    }
+
+    impl<'de, I: Deserialize<'de>> DeserializeSeed<'de> for PathSeed<'_, I> {
+        type Value = I;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<I, D::Error> {
+            if self.path.is_empty() {
+                I::deserialize(deserializer)
+            } else {
+                deserializer.deserialize_map(self)
+            }
+        }
+    }
+
+    impl<'de, I: Deserialize<'de>> Visitor<'de> for PathSeed<'_, I> {
+        type Value = I;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a map containing {:?}", self.path)
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<I, A::Error> {
+            let (key, path) = self.path.split_once('.').unwrap_or((self.path, ""));
+            let mut found = None;
+            while let Some(field) = map.next_key::<String>()? {
+                if field == key && found.is_none() {
+                    found = Some(map.next_value_seed(PathSeed {
+                        path,
+                        marker: std::marker::PhantomData,
+                    })?);
+                } else {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+            // `missing_field` requires a static name, which a path segment isn't
+            found.ok_or_else(|| de::Error::custom(format_args!("missing field `{key}`")))
+        }
+    }
+
+    /// Serializes the id nested at the given dot-separated path
+    pub fn serialize<I, T, S>(
+        id: &TypedId<I, T>,
+        path: &str,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        I: Serialize,
+        S: Serializer,
+    {
+        Nested { path, id: &id.0 }.serialize(serializer)
+    }
+
+    /// Deserializes the id nested at the given dot-separated path
+    pub fn deserialize<'de, I, T, D>(deserializer: D, path: &str) -> Result<TypedId<I, T>, D::Error>
+    where
+        I: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        PathSeed {
+            path,
+            marker: std::marker::PhantomData,
+        }
+        .deserialize(deserializer)
+        .map(TypedId::new)
+    }
+}
+
+/// Generates a serde `with` module for an id nested at a dot-separated path.
+/// See `typed_id::from_path` for an example.
+/// ```rust
+/// // This is turned
+/// typed_id::from_path!(pub, data_id, "data.id");
+/// // into this
+/// // pub mod data_id {
+/// //     pub fn serialize(...) { typed_id::from_path::serialize(id, "data.id", serializer) }
+/// //     pub fn deserialize(...) { typed_id::from_path::deserialize(deserializer, "data.id") }
+/// // }
+/// ```
+#[macro_export]
+macro_rules! from_path {
+    ($name:ident, $path:literal) => {
+        typed_id::from_path!(pub(self), $name, $path);
+    };
+    ($vis:vis, $name:ident, $path:literal) => {
+        $vis mod $name {
+            pub fn serialize<I, T, S>(
+                id: &typed_id::TypedId<I, T>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                I: serde::Serialize,
+                S: serde::Serializer,
+            {
+                typed_id::from_path::serialize(id, $path, serializer)
+            }
+
+            pub fn deserialize<'de, I, T, D>(
+                deserializer: D,
+            ) -> Result<typed_id::TypedId<I, T>, D::Error>
+            where
+                I: serde::Deserialize<'de>,
+                D: serde::Deserializer<'de>,
+            {
+                typed_id::from_path::deserialize(deserializer, $path)
+            }
+        }
+    };
+}
+
+/// A serde `with` helper that (de)serializes integer-backed ids as fixed-width, zero-padded
+/// strings, e.g. `"00000042"` for a width of 8.
+///
+/// Serializing an id that does not fit in the given width is an error. Leading zeros are trimmed
+/// when deserializing.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::{TypedId, ZeroPadded};
+///
+/// struct Customer;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "ZeroPadded::<8>")]
+///     id: TypedId<u32, Customer>,
+/// }
+///
+/// let json = serde_json::to_string(&Record { id: 42.into() }).unwrap();
+/// assert_eq!(json, r#"{"id":"00000042"}"#);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ZeroPadded<const W: usize>;
+
+impl<const W: usize> ZeroPadded<W> {
+    /// Serializes the id as a zero-padded string of width `W`
+    pub fn serialize<I, T, S>(id: &TypedId<I, T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: fmt::Display,
+        S: Serializer,
+    {
+        let padded = format!("{:0W$}", id.0);
+        if padded.len() > W {
+            return Err(ser::Error::custom(format!(
+                "id {padded} does not fit in {W} characters"
+            )));
+        }
+        serializer.serialize_str(&padded)
+    }
+
+    /// Deserializes the id from a zero-padded string
+    pub fn deserialize<'de, I, T, D>(deserializer: D) -> Result<TypedId<I, T>, D::Error>
+    where
+        I: FromStr,
+        I::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        let padded = Cow::<'de, str>::deserialize(deserializer)?;
+        let trimmed = padded.trim_start_matches('0');
+        let digits = if trimmed.is_empty() { "0" } else { trimmed };
+        digits.parse().map(TypedId::new).map_err(de::Error::custom)
+    }
+}
+
+/// A serde `with` helper that (de)serializes `Uuid`-backed ids as a MessagePack extension type
+/// with the given tag containing the 16 raw bytes of the UUID. This is much more compact than the
+/// default 36-character string.
+///
+/// This uses the extension type convention of `rmp-serde`; other formats will see a newtype
+/// struct containing a tag and a byte array.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use typed_id::{MsgPackUuid, TypedId};
+/// use uuid::Uuid;
+///
+/// struct Customer;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "MsgPackUuid::<1>")]
+///     id: TypedId<Uuid, Customer>,
+/// }
+///
+/// let bytes = rmp_serde::to_vec(&Record { id: Uuid::nil().into() }).unwrap();
+/// assert_eq!(bytes.len(), 19);
+/// ```
+#[cfg(feature = "uuid")]
+#[derive(Debug, Clone, Copy)]
+pub struct MsgPackUuid<const TAG: i8>;
+
+#[cfg(feature = "uuid")]
+const MSGPACK_EXT_STRUCT_NAME: &str = "_ExtStruct";
+
+#[cfg(feature = "uuid")]
+impl<const TAG: i8> MsgPackUuid<TAG> {
+    /// Serializes the id as an extension type containing the UUID's bytes
+    pub fn serialize<T, S>(id: &TypedId<uuid::Uuid, T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer
+            .serialize_newtype_struct(MSGPACK_EXT_STRUCT_NAME, &(TAG, UuidBytes(*id.0.as_bytes())))
+    }
+
+    /// Deserializes the id from an extension type containing the UUID's bytes
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<TypedId<uuid::Uuid, T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_newtype_struct(MSGPACK_EXT_STRUCT_NAME, MsgPackUuidVisitor::<TAG>)
+            .map(TypedId::new)
+    }
+}
+
+#[cfg(feature = "uuid")]
+struct UuidBytes([u8; 16]);
+
+#[cfg(feature = "uuid")]
+impl Serialize for UuidBytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<'de> Deserialize<'de> for UuidBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BytesVisitor;
+
+        impl Visitor<'_> for BytesVisitor {
+            type Value = UuidBytes;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("16 bytes")
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                v.try_into()
+                    .map(UuidBytes)
+                    .map_err(|_| E::invalid_length(v.len(), &self))
+            }
+        }
+
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
+
+#[cfg(feature = "uuid")]
+struct MsgPackUuidVisitor<const TAG: i8>;
+
+#[cfg(feature = "uuid")]
+impl<'de, const TAG: i8> Visitor<'de> for MsgPackUuidVisitor<TAG> {
+    type Value = uuid::Uuid;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a MessagePack extension type with tag {TAG}")
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (tag, UuidBytes(bytes)) = <(i8, UuidBytes)>::deserialize(deserializer)?;
+        if tag != TAG {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Signed(tag.into()),
+                &self,
+            ));
+        }
+        Ok(uuid::Uuid::from_bytes(bytes))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::TypedId;
+    use crate::{EitherId, TypedId, ZeroPadded};
     use serde::{Deserialize, Serialize};
 
     type CustomerId = TypedId<u32, Customer>;
@@ -40,7 +971,7 @@ mod tests {
     #[test]
     fn can_map() {
         use std::collections::HashMap;
-        
+
         let map: HashMap<CustomerId, Customer> = (0..10)
             .map(|i| {
                 (
@@ -53,7 +984,371 @@ mod tests {
             })
             .collect();
         let json = serde_json::to_string(&map).expect("Customer");
-        let new_map : HashMap<CustomerId, Customer> = serde_json::from_str(&json).expect("Typed Customer");
+        let new_map: HashMap<CustomerId, Customer> =
+            serde_json::from_str(&json).expect("Typed Customer");
         assert_eq!(new_map, map);
     }
+
+    #[test]
+    fn non_zero_error() {
+        use std::num::NonZeroU32;
+
+        #[derive(Deserialize, Debug)]
+        struct Record {
+            #[serde(deserialize_with = "crate::non_zero::deserialize")]
+            id: TypedId<NonZeroU32, Customer>,
+        }
+
+        let err = serde_json::from_str::<Record>(r#"{ "id": 0 }"#).unwrap_err();
+        assert!(err.to_string().starts_with("CustomerId must be non-zero"));
+
+        let record: Record = serde_json::from_str(r#"{ "id": 42 }"#).unwrap();
+        assert_eq!(record.id.get(), 42);
+    }
+
+    #[test]
+    fn either_round_trip() {
+        let num: EitherId<Customer> = TypedId::<u64, Customer>::new(42).into();
+        let slug: EitherId<Customer> = TypedId::<String, Customer>::new("jane-doe".into()).into();
+
+        let json = serde_json::to_string(&num).unwrap();
+        assert_eq!(json, "42");
+        assert_eq!(
+            serde_json::from_str::<EitherId<Customer>>(&json).unwrap(),
+            num
+        );
+
+        let json = serde_json::to_string(&slug).unwrap();
+        assert_eq!(json, r#""jane-doe""#);
+        assert_eq!(
+            serde_json::from_str::<EitherId<Customer>>(&json).unwrap(),
+            slug
+        );
+
+        assert!(serde_json::from_str::<EitherId<Customer>>("-1").is_err());
+        assert!(serde_json::from_str::<EitherId<Customer>>("[]").is_err());
+    }
+
+    #[test]
+    fn flattened_values() {
+        use std::collections::BTreeMap;
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Directory {
+            name: String,
+            #[serde(flatten)]
+            members: BTreeMap<String, CustomerId>,
+        }
+
+        let directory = Directory {
+            name: "Accounts".into(),
+            members: (0..5).map(|i| (i.to_string(), i.into())).collect(),
+        };
+        let json = serde_json::to_string(&directory).expect("Directory");
+        assert_eq!(json, r#"{"name":"Accounts","0":0,"1":1,"2":2,"3":3,"4":4}"#);
+        let new_directory: Directory = serde_json::from_str(&json).expect("Typed Directory");
+        assert_eq!(new_directory, directory);
+    }
+
+    #[test]
+    fn flattened_composite_key() {
+        struct Order;
+        type OrderId = TypedId<u64, Order>;
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Key {
+            customer: CustomerId,
+            order: OrderId,
+        }
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Line {
+            #[serde(flatten)]
+            key: Key,
+            quantity: u32,
+        }
+
+        let line = Line {
+            key: Key {
+                customer: 1.into(),
+                order: 2.into(),
+            },
+            quantity: 3,
+        };
+        let json = serde_json::to_string(&line).unwrap();
+        assert_eq!(json, r#"{"customer":1,"order":2,"quantity":3}"#);
+        assert_eq!(serde_json::from_str::<Line>(&json).unwrap(), line);
+    }
+
+    #[test]
+    fn config_representations() {
+        use config::{Config, Value};
+
+        #[derive(Deserialize, Debug)]
+        struct Settings {
+            customer: CustomerId,
+        }
+
+        for value in [Value::from(42), Value::from("42")] {
+            let settings: Settings = Config::builder()
+                .set_override("customer", value)
+                .unwrap()
+                .build()
+                .unwrap()
+                .try_deserialize()
+                .unwrap();
+            assert_eq!(settings.customer, 42.into());
+        }
+    }
+
+    #[cfg(feature = "redact")]
+    #[test]
+    fn redacted_round_trip() {
+        let id: crate::RedactedId<u32, Customer> = crate::RedactedId::new(42);
+        assert_eq!(format!("{id:?}"), "CustomerId(***)");
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "42");
+        let new_id: crate::RedactedId<u32, Customer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(new_id, id);
+    }
+
+    #[test]
+    fn zero_padded() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Record {
+            #[serde(with = "ZeroPadded::<4>")]
+            id: CustomerId,
+        }
+
+        let json = serde_json::to_string(&Record { id: 42.into() }).unwrap();
+        assert_eq!(json, r#"{"id":"0042"}"#);
+        let record: Record = serde_json::from_str(&json).unwrap();
+        assert_eq!(record.id, 42.into());
+
+        let record: Record = serde_json::from_str(r#"{"id":"0000"}"#).unwrap();
+        assert_eq!(record.id, 0.into());
+        assert!(serde_json::from_str::<Record>(r#"{"id":"00x2"}"#).is_err());
+
+        let err = serde_json::to_string(&Record { id: 12345.into() }).unwrap_err();
+        assert_eq!(err.to_string(), "id 12345 does not fit in 4 characters");
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn msgpack_uuid() {
+        use crate::MsgPackUuid;
+        use uuid::Uuid;
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Record {
+            #[serde(with = "MsgPackUuid::<7>")]
+            id: TypedId<Uuid, Customer>,
+        }
+
+        let record = Record {
+            id: Uuid::now_v7().into(),
+        };
+        let bytes = rmp_serde::to_vec(&record).unwrap();
+        // A one element array, a fixext16 marker, the tag, then the raw bytes
+        assert_eq!(bytes[..3], [0x91, 0xd8, 7]);
+        assert_eq!(bytes[3..], *record.id.as_bytes());
+        let new_record: Record = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(new_record, record);
+
+        #[derive(Deserialize, Debug)]
+        struct Other {
+            #[serde(with = "MsgPackUuid::<8>")]
+            #[allow(dead_code)]
+            id: TypedId<Uuid, Customer>,
+        }
+        assert!(rmp_serde::from_slice::<Other>(&bytes).is_err());
+    }
+
+    #[test]
+    fn csv_list() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Query {
+            #[serde(with = "crate::csv_list")]
+            ids: Vec<CustomerId>,
+        }
+
+        let query = Query {
+            ids: (1..=3).map(Into::into).collect(),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"ids":"1,2,3"}"#);
+        assert_eq!(serde_json::from_str::<Query>(&json).unwrap(), query);
+
+        let empty = Query { ids: Vec::new() };
+        let json = serde_json::to_string(&empty).unwrap();
+        assert_eq!(json, r#"{"ids":""}"#);
+        assert_eq!(serde_json::from_str::<Query>(&json).unwrap(), empty);
+
+        assert!(serde_json::from_str::<Query>(r#"{"ids":"1,,3"}"#).is_err());
+    }
+
+    #[test]
+    fn check_digit() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Record {
+            #[serde(with = "crate::check_digit")]
+            id: TypedId<u64, Customer>,
+        }
+
+        let record = Record {
+            id: 7992739871.into(),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(json, r#"{"id":"79927398713"}"#);
+
+        for raw in [0, 7, 42, 1000, 123_456_789, u64::MAX] {
+            let record = Record { id: raw.into() };
+            let json = serde_json::to_string(&record).unwrap();
+            assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+            assert_eq!(serde_json::to_string(&record).unwrap(), json);
+        }
+
+        // A single corrupted digit and two swapped digits are both caught
+        let err = serde_json::from_str::<Record>(r#"{"id":"79927398813"}"#).unwrap_err();
+        assert!(err.to_string().contains("invalid check digit"));
+        assert!(serde_json::from_str::<Record>(r#"{"id":"79927398173"}"#).is_err());
+        assert!(serde_json::from_str::<Record>(r#"{"id":"7"}"#).is_err());
+        assert!(serde_json::from_str::<Record>(r#"{"id":"-42"}"#).is_err());
+
+        #[derive(Serialize)]
+        struct Signed {
+            #[serde(with = "crate::check_digit")]
+            id: TypedId<i32, Customer>,
+        }
+        assert!(serde_json::to_string(&Signed { id: (-42).into() }).is_err());
+    }
+
+    #[test]
+    fn sorted_set() {
+        use std::collections::HashSet;
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Segment {
+            #[serde(with = "crate::sorted_set")]
+            members: HashSet<CustomerId>,
+        }
+
+        // Sets with different insertion orders and capacities iterate in different orders
+        let forward = Segment {
+            members: (0..100).map(CustomerId::new).collect(),
+        };
+        let mut members = HashSet::with_capacity(1000);
+        members.extend((0..100).rev().map(CustomerId::new));
+        let backward = Segment { members };
+
+        let json = serde_json::to_string(&forward).unwrap();
+        assert_eq!(json, serde_json::to_string(&backward).unwrap());
+        assert!(json.starts_with(r#"{"members":[0,1,2,"#));
+        assert_eq!(serde_json::from_str::<Segment>(&json).unwrap(), forward);
+    }
+
+    #[test]
+    fn deserialize_in_place() {
+        let mut id: TypedId<String, Customer> = String::with_capacity(64).into();
+        let buffer = id.as_ptr();
+
+        let mut de = serde_json::Deserializer::from_str(r#""jane-doe""#);
+        TypedId::deserialize_in_place(&mut de, &mut id).unwrap();
+        assert_eq!(*id, "jane-doe");
+        assert_eq!(id.as_ptr(), buffer);
+
+        let mut ids: Vec<CustomerId> = vec![0.into(); 2];
+        let mut de = serde_json::Deserializer::from_str("[4, 2, 7]");
+        Vec::deserialize_in_place(&mut de, &mut ids).unwrap();
+        assert_eq!(ids, [4, 2, 7].map(CustomerId::new));
+
+        let mut de = serde_json::Deserializer::from_str("[4, -2]");
+        assert!(Vec::deserialize_in_place(&mut de, &mut ids).is_err());
+    }
+
+    #[test]
+    fn prefixed() {
+        struct LineItem;
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Event {
+            #[serde(with = "crate::prefixed")]
+            customer: CustomerId,
+            #[serde(with = "crate::prefixed")]
+            item: TypedId<String, LineItem>,
+        }
+
+        let event = Event {
+            customer: 42.into(),
+            item: String::from("sku-7").into(),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"customer":"customer:42","item":"line_item:sku-7"}"#
+        );
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+
+        let err =
+            serde_json::from_str::<Event>(r#"{"customer":"order:42","item":"line_item:sku-7"}"#)
+                .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with(r#"expected an id prefixed with "customer", found "order""#));
+        assert!(
+            serde_json::from_str::<Event>(r#"{"customer":"42","item":"line_item:a"}"#).is_err()
+        );
+        assert!(
+            serde_json::from_str::<Event>(r#"{"customer":"customer:x","item":"line_item:a"}"#)
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "js-safe-number")]
+    #[test]
+    fn js_safe_number() {
+        use crate::js_safe_number::MAX_SAFE_INTEGER;
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Record {
+            #[serde(with = "crate::js_safe_number")]
+            id: TypedId<u64, Customer>,
+        }
+
+        let safe = Record {
+            id: MAX_SAFE_INTEGER.into(),
+        };
+        let json = serde_json::to_string(&safe).unwrap();
+        assert_eq!(json, r#"{"id":9007199254740991}"#);
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), safe);
+
+        let unsafe_id = Record {
+            id: (MAX_SAFE_INTEGER + 1).into(),
+        };
+        let json = serde_json::to_string(&unsafe_id).unwrap();
+        assert_eq!(json, r#"{"id":"9007199254740992"}"#);
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), unsafe_id);
+
+        let record: Record = serde_json::from_str(r#"{"id":"42"}"#).unwrap();
+        assert_eq!(record.id, 42.into());
+        assert!(serde_json::from_str::<Record>(r#"{"id":-1}"#).is_err());
+        assert!(serde_json::from_str::<Record>(r#"{"id":"forty two"}"#).is_err());
+    }
+
+    #[test]
+    fn null_as_zero() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            #[serde(with = "crate::null_as_zero")]
+            id: CustomerId,
+        }
+
+        let record: Record = serde_json::from_str(r#"{"id":null}"#).unwrap();
+        assert_eq!(record.id, CustomerId::new(0));
+        assert_eq!(serde_json::to_string(&record).unwrap(), r#"{"id":null}"#);
+
+        let record: Record = serde_json::from_str(r#"{"id":42}"#).unwrap();
+        assert_eq!(record.id, CustomerId::new(42));
+        assert_eq!(serde_json::to_string(&record).unwrap(), r#"{"id":42}"#);
+    }
 }
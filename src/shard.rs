@@ -0,0 +1,117 @@
+//! This module adds deterministic shard assignment for typed ids.
+//!
+//! The standard `HashMap` hasher is randomly seeded per process, so it can't be used to pick a
+//! shard that must stay the same across restarts. Instead, ids are hashed with FNV-1a, which has
+//! no seed. Integers are fed to the hasher as little-endian bytes, so the assignment is also the
+//! same on every platform.
+
+use std::hash::{Hash, Hasher};
+
+use crate::TypedId;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// An unseeded FNV-1a hasher
+struct StableHasher(u64);
+
+impl StableHasher {
+    fn new() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+macro_rules! write_le {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(&mut self, i: $ty) {
+                self.write(&i.to_le_bytes());
+            }
+        )*
+    };
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    write_le!(
+        write_u16(u16),
+        write_u32(u32),
+        write_u64(u64),
+        write_u128(u128),
+        write_i16(i16),
+        write_i32(i32),
+        write_i64(i64),
+        write_i128(i128),
+    );
+
+    // The width of `usize` differs between platforms, so it is always hashed as a `u64`
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
+
+impl<I: Hash, T> TypedId<I, T> {
+    /// Returns the shard, in `0..shards`, that this id belongs to.
+    ///
+    /// The assignment only depends on the underlying id, so it is the same in every process and
+    /// on every platform. Note, the marker type is not part of the hash.
+    ///
+    /// # Panics
+    /// Panics if `shards` is zero.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let id: TypedId<u32, Customer> = 42.into();
+    /// assert!(id.shard_of(16) < 16);
+    /// assert_eq!(id.shard_of(16), TypedId::<u32, Customer>::new(42).shard_of(16));
+    /// ```
+    pub fn shard_of(&self, shards: usize) -> usize {
+        assert!(shards > 0, "can not assign an id to one of zero shards");
+        let mut hasher = StableHasher::new();
+        self.0.hash(&mut hasher);
+        (hasher.finish() % shards as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::{Hash, Hasher};
+
+    use super::StableHasher;
+    use crate::TypedId;
+
+    struct Customer;
+
+    #[test]
+    fn deterministic_shards() {
+        let id: TypedId<u32, Customer> = 42.into();
+        let mut first = StableHasher::new();
+        let mut second = StableHasher::new();
+        id.hash(&mut first);
+        id.hash(&mut second);
+        assert_eq!(first.finish(), second.finish());
+
+        // Pinned, so any change to the hashing is caught before it moves existing data
+        assert_eq!(first.finish(), 0x8d9a_adc8_352f_df7f);
+        assert_eq!(id.shard_of(8), 7);
+        assert_eq!(id.shard_of(8), id.shard_of(8));
+
+        let slug: TypedId<&str, Customer> = TypedId::new("jane-doe");
+        assert_eq!(slug.shard_of(1), 0);
+        assert_eq!(slug.shard_of(100), slug.shard_of(100));
+    }
+}
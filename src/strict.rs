@@ -0,0 +1,256 @@
+//! A serde `with` helper for scalar ids that names the id in invalid type errors.
+//!
+//! By default, a typed id is deserialized exactly like its underlying type, so a malformed payload
+//! like `{"id": 42}` where a bare `42` is expected produces the underlying type's error, which
+//! doesn't mention the id. With this helper, the deserializer is wrapped such that finding a map or
+//! a sequence where the id was expected produces an error naming the id, e.g. "invalid type: map,
+//! expected CustomerId (a u32)".
+//!
+//! Most formats report the invalid type themselves before calling into the visitor, so the error
+//! is worded by serde (`invalid type: ..., expected ...`) and the id is named in the "expected"
+//! half of the message.
+//!
+//! Note, this assumes that the underlying id is a scalar, so it is opt-in per field. Ids backed by
+//! types that deserialize from maps or sequences, like tuples or byte arrays, can not use it.
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//! use typed_id::TypedId;
+//!
+//! struct Customer;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "typed_id::strict")]
+//!     id: TypedId<u32, Customer>,
+//! }
+//!
+//! let err = serde_json::from_str::<Record>(r#"{"id":{"id":42}}"#).err().unwrap();
+//! assert!(err.to_string().contains("expected CustomerId (a u32)"));
+//! ```
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, Deserializer, EnumAccess, MapAccess, SeqAccess, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+
+use crate::{marker_name, TypedId};
+
+/// Serializes the id exactly like its underlying id
+pub fn serialize<I, T, S>(id: &TypedId<I, T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    I: Serialize,
+    S: Serializer,
+{
+    id.0.serialize(serializer)
+}
+
+/// Deserializes the id, rejecting maps and sequences with an error that names the id
+pub fn deserialize<'de, I, T, D>(deserializer: D) -> Result<TypedId<I, T>, D::Error>
+where
+    I: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    I::deserialize(StrictDeserializer::<_, I, T>::new(deserializer)).map(TypedId::new)
+}
+
+/// Wraps a deserializer, rejecting maps and sequences where a scalar id was expected.
+struct StrictDeserializer<D, I, T> {
+    inner: D,
+    marker: PhantomData<fn() -> (I, T)>,
+}
+
+impl<D, I, T> StrictDeserializer<D, I, T> {
+    fn new(inner: D) -> Self {
+        Self {
+            inner,
+            marker: PhantomData,
+        }
+    }
+}
+
+struct StrictVisitor<V, I, T> {
+    inner: V,
+    marker: PhantomData<fn() -> (I, T)>,
+}
+
+impl<V, I, T> StrictVisitor<V, I, T> {
+    fn new(inner: V) -> Self {
+        Self {
+            inner,
+            marker: PhantomData,
+        }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.inner
+                    .$method($($arg,)* StrictVisitor::<V, I, T>::new(visitor))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>, I, T> Deserializer<'de> for StrictDeserializer<D, I, T> {
+    type Error = D::Error;
+
+    forward_deserialize!(
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    );
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>, I, T> Visitor<'de> for StrictVisitor<V, I, T> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}Id (a {})", marker_name::<T>(), marker_name::<I>())
+    }
+
+    forward_visit!(
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_string(String),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    );
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D2: Deserializer<'de>>(self, deserializer: D2) -> Result<Self::Value, D2::Error> {
+        self.inner.visit_some(deserializer)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D2: Deserializer<'de>>(
+        self,
+        deserializer: D2,
+    ) -> Result<Self::Value, D2::Error> {
+        self.inner.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, _: A) -> Result<Self::Value, A::Error> {
+        Err(de::Error::invalid_type(de::Unexpected::Seq, &self))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, _: A) -> Result<Self::Value, A::Error> {
+        Err(de::Error::invalid_type(de::Unexpected::Map, &self))
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_enum(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::TypedId;
+
+    struct Customer;
+
+    type CustomerId = TypedId<u32, Customer>;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Record {
+        #[serde(with = "super")]
+        id: CustomerId,
+    }
+
+    #[test]
+    fn object_instead_of_scalar() {
+        let err = serde_json::from_str::<Record>(r#"{"id": {"id": 42}}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid type: map, expected CustomerId (a u32)"));
+
+        let err = serde_json::from_str::<Record>(r#"{"id": [42]}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid type: sequence, expected CustomerId (a u32)"));
+
+        let record: Record = serde_json::from_str(r#"{"id": 42}"#).unwrap();
+        assert_eq!(record.id, CustomerId::new(42));
+        assert_eq!(serde_json::to_string(&record).unwrap(), r#"{"id":42}"#);
+    }
+
+    #[test]
+    fn default_impl_is_unchanged() {
+        // Only fields that opt in are strict, so ids backed by sequences still deserialize
+        let id: TypedId<(u32, u32), Customer> = serde_json::from_str("[4, 2]").unwrap();
+        assert_eq!(*id, (4, 2));
+        let id: TypedId<[u8; 2], Customer> = serde_json::from_str("[4, 2]").unwrap();
+        assert_eq!(*id, [4, 2]);
+    }
+}
@@ -0,0 +1,85 @@
+//! This module adds helpers for recording `TypedId`s as structured `tracing` fields.
+//!
+//! tracing's `Value` trait is sealed, so it can not be implemented for `TypedId`. Instead,
+//! `TypedId::as_value` exposes the underlying id, which is recorded with its own type rather than
+//! being formatted via `%id` or `?id`.
+
+use tracing::Value;
+
+use crate::TypedId;
+
+impl<I: Value, T> TypedId<I, T> {
+    /// Returns the underlying id as a structured `tracing` value.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// let id: TypedId<u64, Customer> = 42.into();
+    /// tracing::info!(customer_id = id.as_value(), "Customer logged in");
+    /// ```
+    pub fn as_value(&self) -> &I {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fmt,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use crate::TypedId;
+
+    struct Customer;
+
+    #[derive(Default, Clone)]
+    struct Recorder(Arc<Mutex<Vec<(String, u64)>>>);
+
+    impl Visit for Recorder {
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            self.0.lock().unwrap().push((field.name().into(), value));
+        }
+
+        fn record_debug(&mut self, _: &Field, _: &dyn fmt::Debug) {}
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut self.clone())
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    #[test]
+    fn records_inner_value() {
+        let recorder = Recorder::default();
+        let id: TypedId<u64, Customer> = 42.into();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            tracing::info!(customer_id = id.as_value());
+        });
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [("customer_id".to_owned(), 42)]
+        );
+    }
+}
@@ -0,0 +1,352 @@
+//! A serde `with` helper that trims whitespace around string input before it reaches the id.
+//!
+//! Pipelines that convert CSV into JSON often produce ids like `" 42 "`. By default, a typed id is
+//! deserialized exactly like its underlying type, so that whitespace is kept for string ids and is
+//! an error for integer ids. With this helper, the deserializer is wrapped such that string input
+//! is trimmed before it reaches the underlying type. When a human-readable format gives a string
+//! where a number was expected, the trimmed string is parsed as that number.
+//!
+//! Non-human-readable formats can not describe their own contents, so only string ids are trimmed
+//! for them.
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//! use typed_id::TypedId;
+//!
+//! struct Customer;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "typed_id::trim")]
+//!     id: TypedId<u32, Customer>,
+//! }
+//!
+//! let record: Record = serde_json::from_str(r#"{"id":" 42 "}"#).unwrap();
+//! assert_eq!(record.id, TypedId::new(42));
+//! ```
+
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+use serde::{
+    de::{self, Deserializer, EnumAccess, MapAccess, SeqAccess, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+
+use crate::TypedId;
+
+/// Serializes the id exactly like its underlying id
+pub fn serialize<I, T, S>(id: &TypedId<I, T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    I: Serialize,
+    S: Serializer,
+{
+    id.0.serialize(serializer)
+}
+
+/// Deserializes the id, trimming whitespace from string input first
+pub fn deserialize<'de, I, T, D>(deserializer: D) -> Result<TypedId<I, T>, D::Error>
+where
+    I: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    I::deserialize(TrimDeserializer::new(deserializer)).map(TypedId::new)
+}
+
+/// Wraps a deserializer, trimming whitespace from string input.
+struct TrimDeserializer<D> {
+    inner: D,
+}
+
+impl<D> TrimDeserializer<D> {
+    fn new(inner: D) -> Self {
+        Self { inner }
+    }
+}
+
+/// A primitive that can be parsed from a trimmed string and handed to a visitor.
+trait Primitive: FromStr {
+    fn visit<'de, V: Visitor<'de>, E: de::Error>(self, visitor: V) -> Result<V::Value, E>;
+}
+
+macro_rules! impl_primitive {
+    ($($ty:ty => $visit:ident),* $(,)?) => {
+        $(
+            impl Primitive for $ty {
+                fn visit<'de, V: Visitor<'de>, E: de::Error>(self, visitor: V) -> Result<V::Value, E> {
+                    visitor.$visit(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive!(
+    i8 => visit_i8,
+    i16 => visit_i16,
+    i32 => visit_i32,
+    i64 => visit_i64,
+    i128 => visit_i128,
+    u8 => visit_u8,
+    u16 => visit_u16,
+    u32 => visit_u32,
+    u64 => visit_u64,
+    u128 => visit_u128,
+    f32 => visit_f32,
+    f64 => visit_f64,
+);
+
+/// Visits a number, parsing it from a trimmed string if that is what the format contains.
+struct ParseVisitor<V, P> {
+    inner: V,
+    marker: PhantomData<fn() -> P>,
+}
+
+/// Visits a string, trimming it before it is handed to the wrapped visitor.
+struct TrimVisitor<V> {
+    inner: V,
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.inner.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+macro_rules! parse_trimmed {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, D::Error>
+            where
+                V: Visitor<'de>,
+            {
+                if self.inner.is_human_readable() {
+                    self.inner.deserialize_any(ParseVisitor::<V, $ty> {
+                        inner: visitor,
+                        marker: PhantomData,
+                    })
+                } else {
+                    self.inner.$method(visitor)
+                }
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for TrimDeserializer<D> {
+    type Error = D::Error;
+
+    forward_deserialize!(
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_char(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    );
+
+    parse_trimmed!(
+        deserialize_i8(i8),
+        deserialize_i16(i16),
+        deserialize_i32(i32),
+        deserialize_i64(i64),
+        deserialize_i128(i128),
+        deserialize_u8(u8),
+        deserialize_u16(u16),
+        deserialize_u32(u32),
+        deserialize_u64(u64),
+        deserialize_u128(u128),
+        deserialize_f32(f32),
+        deserialize_f64(f64),
+    );
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner.deserialize_str(TrimVisitor { inner: visitor })
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_string(TrimVisitor { inner: visitor })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+macro_rules! forward_non_str_visits {
+    () => {
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.inner.expecting(f)
+        }
+
+        forward_visit!(
+            visit_bool(bool),
+            visit_i8(i8),
+            visit_i16(i16),
+            visit_i32(i32),
+            visit_i64(i64),
+            visit_i128(i128),
+            visit_u8(u8),
+            visit_u16(u16),
+            visit_u32(u32),
+            visit_u64(u64),
+            visit_u128(u128),
+            visit_f32(f32),
+            visit_f64(f64),
+            visit_char(char),
+            visit_bytes(&[u8]),
+            visit_borrowed_bytes(&'de [u8]),
+            visit_byte_buf(Vec<u8>),
+        );
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            self.inner.visit_none()
+        }
+
+        fn visit_some<D2: Deserializer<'de>>(
+            self,
+            deserializer: D2,
+        ) -> Result<Self::Value, D2::Error> {
+            self.inner.visit_some(deserializer)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            self.inner.visit_unit()
+        }
+
+        fn visit_newtype_struct<D2: Deserializer<'de>>(
+            self,
+            deserializer: D2,
+        ) -> Result<Self::Value, D2::Error> {
+            self.inner.visit_newtype_struct(deserializer)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+            self.inner.visit_seq(seq)
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            self.inner.visit_map(map)
+        }
+
+        fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+            self.inner.visit_enum(data)
+        }
+    };
+}
+
+impl<'de, V: Visitor<'de>, P: Primitive> Visitor<'de> for ParseVisitor<V, P> {
+    type Value = V::Value;
+
+    forward_non_str_visits!();
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        match v.trim().parse::<P>() {
+            Ok(parsed) => parsed.visit(self.inner),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for TrimVisitor<V> {
+    type Value = V::Value;
+
+    forward_non_str_visits!();
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.inner.visit_str(v.trim())
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        self.inner.visit_borrowed_str(v.trim())
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        // Only give up the owned string if there is something to trim
+        if v.trim().len() == v.len() {
+            self.inner.visit_string(v)
+        } else {
+            self.inner.visit_str(v.trim())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::TypedId;
+
+    struct Customer;
+
+    type CustomerId = TypedId<u32, Customer>;
+
+    #[derive(Deserialize)]
+    #[serde(bound = "I: Deserialize<'de>")]
+    struct Record<I> {
+        #[serde(with = "super")]
+        id: TypedId<I, Customer>,
+    }
+
+    fn parse<'de, I: Deserialize<'de>>(json: &'de str) -> serde_json::Result<TypedId<I, Customer>> {
+        serde_json::from_str::<Record<I>>(json).map(|record| record.id)
+    }
+
+    #[test]
+    fn padded_ids() {
+        assert_eq!(
+            parse::<u32>(r#"{"id":" 42 "}"#).unwrap(),
+            CustomerId::new(42)
+        );
+        assert_eq!(parse::<u32>(r#"{"id":42}"#).unwrap(), CustomerId::new(42));
+
+        let id = parse::<String>(r#"{"id":"\tjane-doe "}"#).unwrap();
+        assert_eq!(*id, "jane-doe");
+        let id = parse::<&str>(r#"{"id":" jane-doe"}"#).unwrap();
+        assert_eq!(*id, "jane-doe");
+
+        let err = parse::<u32>(r#"{"id":" 4 2 "}"#).unwrap_err();
+        assert!(err.to_string().starts_with("invalid value: string"));
+        assert!(parse::<u32>(r#"{"id":"-1"}"#).is_err());
+    }
+
+    #[test]
+    fn untouched_without_helper() {
+        assert!(serde_json::from_str::<CustomerId>(r#"" 42 ""#).is_err());
+        let id: TypedId<String, Customer> = serde_json::from_str(r#"" jane ""#).unwrap();
+        assert_eq!(*id, " jane ");
+    }
+}
@@ -0,0 +1,45 @@
+//! This module adds helpers for `TypedId`s whose underlying type is a `Ulid`.
+
+use std::time::SystemTime;
+
+use ulid::Ulid;
+
+use crate::TypedId;
+
+impl<T> TypedId<Ulid, T> {
+    /// Returns the time embedded in the underlying ULID.
+    ///
+    /// Every ULID contains a millisecond-precision timestamp, so this is always `Some`. The
+    /// `Option` mirrors the `Uuid` version of this method, whose UUIDs might not be time-based.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// use ulid::Ulid;
+    ///
+    /// let id: TypedId<Ulid, Customer> = Ulid::new().into();
+    /// assert!(id.timestamp().is_some());
+    /// ```
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        Some(self.0.datetime())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use ulid::Ulid;
+
+    use crate::TypedId;
+
+    struct Customer;
+
+    #[test]
+    fn ulid_timestamp() {
+        let id: TypedId<Ulid, Customer> = Ulid::new().into();
+        let elapsed = SystemTime::now()
+            .duration_since(id.timestamp().unwrap())
+            .unwrap();
+        assert!(elapsed < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,154 @@
+//! This module adds helpers for `TypedId`s whose underlying type is a `Uuid`.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use uuid::{
+    fmt::{Braced, Hyphenated, Simple, Urn},
+    Uuid,
+};
+
+use crate::{LenError, TypedId};
+
+impl<T> TypedId<Uuid, T> {
+    /// Returns the time embedded in the underlying UUID.
+    ///
+    /// Only time-based UUIDs (versions 1, 6, and 7) contain a timestamp. For every other version,
+    /// this returns `None`. Note that version 7 UUIDs only store millisecond precision.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// use uuid::Uuid;
+    ///
+    /// let id: TypedId<Uuid, Customer> = Uuid::now_v7().into();
+    /// assert!(id.timestamp().is_some());
+    ///
+    /// let id: TypedId<Uuid, Customer> = Uuid::nil().into();
+    /// assert!(id.timestamp().is_none());
+    /// ```
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        let (secs, nanos) = self.0.get_timestamp()?.to_unix();
+        UNIX_EPOCH.checked_add(Duration::new(secs, nanos))
+    }
+
+    /// Returns an adapter that formats the underlying UUID without hyphens, e.g.
+    /// `67e5504410b1426f9247bb680e5fe0c8`.
+    /// ```rust
+    /// # struct Customer;
+    /// use typed_id::TypedId;
+    /// use uuid::Uuid;
+    ///
+    /// let id: TypedId<Uuid, Customer> = Uuid::max().into();
+    /// assert_eq!(id.as_simple().to_string(), "f".repeat(32));
+    /// ```
+    #[inline]
+    pub fn as_simple(&self) -> &Simple {
+        self.0.as_simple()
+    }
+
+    /// Returns an adapter that formats the underlying UUID with hyphens, e.g.
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8`. This matches the id's `Display` output.
+    #[inline]
+    pub fn as_hyphenated(&self) -> &Hyphenated {
+        self.0.as_hyphenated()
+    }
+
+    /// Returns an adapter that formats the underlying UUID as a URN, e.g.
+    /// `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    #[inline]
+    pub fn as_urn(&self) -> &Urn {
+        self.0.as_urn()
+    }
+
+    /// Returns an adapter that formats the underlying UUID in braces, e.g.
+    /// `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+    #[inline]
+    pub fn as_braced(&self) -> &Braced {
+        self.0.as_braced()
+    }
+}
+
+impl<T> From<[u8; 16]> for TypedId<Uuid, T> {
+    #[inline]
+    fn from(bytes: [u8; 16]) -> Self {
+        TypedId::new(Uuid::from_bytes(bytes))
+    }
+}
+
+/// Reads the id from a binary column or buffer, which must be exactly 16 bytes long.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{LenError, TypedId};
+/// use uuid::Uuid;
+///
+/// let id = TypedId::<Uuid, Customer>::try_from(&[0u8; 16][..]).unwrap();
+/// assert!(id.is_nil());
+/// let err = TypedId::<Uuid, Customer>::try_from(&[0u8; 15][..]).unwrap_err();
+/// assert_eq!(err, LenError { expected: 16, got: 15 });
+/// ```
+impl<T> TryFrom<&[u8]> for TypedId<Uuid, T> {
+    type Error = LenError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 16] = bytes.try_into().map_err(|_| LenError {
+            expected: 16,
+            got: bytes.len(),
+        })?;
+        Ok(bytes.into())
+    }
+}
+
+impl<T> TryFrom<Vec<u8>> for TypedId<Uuid, T> {
+    type Error = LenError;
+
+    #[inline]
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use uuid::Uuid;
+
+    use crate::TypedId;
+
+    struct Customer;
+
+    #[test]
+    fn v7_timestamp() {
+        let id: TypedId<Uuid, Customer> = Uuid::now_v7().into();
+        let elapsed = SystemTime::now()
+            .duration_since(id.timestamp().unwrap())
+            .unwrap();
+        assert!(elapsed < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn display_formats() {
+        let raw = Uuid::now_v7();
+        let id: TypedId<Uuid, Customer> = raw.into();
+        assert_eq!(id.as_simple().to_string(), raw.simple().to_string());
+        assert_eq!(id.as_hyphenated().to_string(), raw.hyphenated().to_string());
+        assert_eq!(id.as_hyphenated().to_string(), id.to_string());
+        assert_eq!(id.as_urn().to_string(), raw.urn().to_string());
+        assert_eq!(id.as_braced().to_string(), raw.braced().to_string());
+    }
+
+    #[test]
+    fn from_bytes() {
+        let raw = Uuid::now_v7();
+        let id = TypedId::<Uuid, Customer>::try_from(raw.as_bytes().as_slice()).unwrap();
+        assert_eq!(*id, raw);
+        let id = TypedId::<Uuid, Customer>::try_from(raw.as_bytes().to_vec()).unwrap();
+        assert_eq!(*id, raw);
+        assert_eq!(TypedId::<Uuid, Customer>::from(*raw.as_bytes()), id);
+
+        let err = TypedId::<Uuid, Customer>::try_from(vec![0; 15]).unwrap_err();
+        assert_eq!((err.expected, err.got), (16, 15));
+
+        let id: TypedId<[u8; 4], Customer> = [1, 2, 3, 4].into();
+        assert_eq!(*id, [1, 2, 3, 4]);
+    }
+}
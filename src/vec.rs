@@ -0,0 +1,124 @@
+//! This module contains `TypedVec`, a collection of typed ids that all share a marker type.
+
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use crate::TypedId;
+
+/// A `Vec` of typed ids. Dereferences to the underlying `Vec`, but can also be extended with raw
+/// ids, which are wrapped with the marker automatically.
+/// ```rust
+/// # struct Customer;
+/// use typed_id::{TypedId, TypedVec};
+/// let mut ids: TypedVec<u32, Customer> = TypedVec::new();
+/// ids.extend([1, 2]);
+/// ids.extend([TypedId::new(3)]);
+/// assert_eq!(*ids, [1.into(), 2.into(), 3.into()]);
+/// ```
+pub struct TypedVec<I, T>(Vec<TypedId<I, T>>);
+
+impl<I, T> TypedVec<I, T> {
+    /// Creates a new, empty vec
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Creates a new, empty vec with at least the specified capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    /// Consumes this wrapper, returning the underlying `Vec`
+    pub fn into_inner(self) -> Vec<TypedId<I, T>> {
+        self.0
+    }
+}
+
+impl<I, T> Default for TypedVec<I, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: fmt::Debug, T> fmt::Debug for TypedVec<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+impl<I: Clone, T> Clone for TypedVec<I, T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<I: PartialEq, T> PartialEq for TypedVec<I, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<I: Eq, T> Eq for TypedVec<I, T> {}
+
+impl<I, T> Deref for TypedVec<I, T> {
+    type Target = Vec<TypedId<I, T>>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<I, T> DerefMut for TypedVec<I, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<I, T> From<Vec<TypedId<I, T>>> for TypedVec<I, T> {
+    fn from(other: Vec<TypedId<I, T>>) -> Self {
+        Self(other)
+    }
+}
+
+impl<I, T> Extend<I> for TypedVec<I, T> {
+    fn extend<Iter: IntoIterator<Item = I>>(&mut self, iter: Iter) {
+        self.0.extend(iter.into_iter().map(TypedId::new))
+    }
+}
+
+impl<I, T> Extend<TypedId<I, T>> for TypedVec<I, T> {
+    fn extend<Iter: IntoIterator<Item = TypedId<I, T>>>(&mut self, iter: Iter) {
+        self.0.extend(iter)
+    }
+}
+
+impl<I, T> FromIterator<I> for TypedVec<I, T> {
+    fn from_iter<Iter: IntoIterator<Item = I>>(iter: Iter) -> Self {
+        Self(iter.into_iter().map(TypedId::new).collect())
+    }
+}
+
+impl<I, T> FromIterator<TypedId<I, T>> for TypedVec<I, T> {
+    fn from_iter<Iter: IntoIterator<Item = TypedId<I, T>>>(iter: Iter) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<I, T> IntoIterator for TypedVec<I, T> {
+    type Item = TypedId<I, T>;
+    type IntoIter = std::vec::IntoIter<TypedId<I, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, I, T> IntoIterator for &'a TypedVec<I, T> {
+    type Item = &'a TypedId<I, T>;
+    type IntoIter = std::slice::Iter<'a, TypedId<I, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
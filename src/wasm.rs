@@ -0,0 +1,86 @@
+//! This implements the conversions between typed ids and JavaScript values for `wasm-bindgen`.
+//!
+//! `u32` ids cross the boundary as JavaScript numbers, which can hold every `u32` exactly. String
+//! ids cross the boundary as `JsString`s. Wider integer ids are not converted since JavaScript
+//! numbers can not represent all of them; see the `js-safe-number` feature for serde.
+//!
+//! Note, functions exported with `#[wasm_bindgen]` can't take or return a `TypedId` directly. Take
+//! and return a `JsValue` instead, converting with `as_js` and `try_from`.
+
+use js_sys::JsString;
+use wasm_bindgen::JsValue;
+
+use crate::TypedId;
+
+impl<I: Clone, T> TypedId<I, T>
+where
+    JsValue: From<I>,
+{
+    /// Converts the underlying id into a `JsValue`, for returning it from a `#[wasm_bindgen]`
+    /// function.
+    pub fn as_js(&self) -> JsValue {
+        JsValue::from(self.0.clone())
+    }
+}
+
+impl<T> From<TypedId<u32, T>> for JsValue {
+    fn from(id: TypedId<u32, T>) -> Self {
+        JsValue::from(id.0)
+    }
+}
+
+impl<T> TryFrom<JsValue> for TypedId<u32, T> {
+    /// The original value, if it was not a number that fits in a `u32`
+    type Error = JsValue;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        match value.as_f64() {
+            Some(num) if num.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(&num) => {
+                Ok(Self::new(num as u32))
+            }
+            _ => Err(value),
+        }
+    }
+}
+
+impl<T> From<TypedId<String, T>> for JsString {
+    fn from(id: TypedId<String, T>) -> Self {
+        JsString::from(id.0)
+    }
+}
+
+impl<T> From<JsString> for TypedId<String, T> {
+    fn from(value: JsString) -> Self {
+        Self::new(value.into())
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use js_sys::JsString;
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::TypedId;
+
+    struct Customer;
+
+    type CustomerId = TypedId<u32, Customer>;
+
+    #[wasm_bindgen_test]
+    fn round_trip_through_js_value() {
+        let id = CustomerId::new(42);
+        let value: JsValue = id.into();
+        assert_eq!(value.as_f64(), Some(42.0));
+        assert_eq!(CustomerId::try_from(value), Ok(id));
+        assert_eq!(id.as_js(), JsValue::from(42));
+
+        assert!(CustomerId::try_from(JsValue::from(-1)).is_err());
+        assert!(CustomerId::try_from(JsValue::from(1.5)).is_err());
+        assert!(CustomerId::try_from(JsValue::from("42")).is_err());
+
+        let id = TypedId::<String, Customer>::new("jane-doe".into());
+        let value: JsString = id.clone().into();
+        assert_eq!(TypedId::<String, Customer>::from(value), id);
+    }
+}
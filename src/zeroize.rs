@@ -0,0 +1,43 @@
+//! This implements `Zeroize` for typed ids, so that sensitive ids, like session tokens, can be
+//! cleared from memory.
+//!
+//! `ZeroizeOnDrop` is not implemented since it requires a `Drop` impl, which would stop every
+//! `TypedId` from being `Copy`. To clear an id when it is dropped, wrap it in `Zeroizing`.
+//! ```rust
+//! # struct Session;
+//! use typed_id::TypedId;
+//! use zeroize::Zeroizing;
+//!
+//! let token = Zeroizing::new(TypedId::<String, Session>::new("s3cr3t".into()));
+//! assert_eq!(**token, "s3cr3t");
+//! ```
+
+use zeroize::Zeroize;
+
+use crate::TypedId;
+
+impl<I: Zeroize, T> Zeroize for TypedId<I, T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zeroize::Zeroize;
+
+    use crate::TypedId;
+
+    struct Session;
+
+    #[test]
+    fn zeroize_inner() {
+        let mut token = TypedId::<Vec<u8>, Session>::new(vec![1, 2, 3]);
+        token.zeroize();
+        assert!(token.is_empty());
+
+        let mut token = TypedId::<[u8; 4], Session>::new([1, 2, 3, 4]);
+        token.zeroize();
+        assert_eq!(*token, [0; 4]);
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use typed_id::id_type;
+    use typed_id::{
+        assert_distinct_ids, id_type, zip_ids, IdExt, IdIteratorExt, OptionIdExt, ResultIdExt,
+        TypedIdIterExt, TypedIdSliceExt, TypedIdVecExt, ZipLengthError,
+    };
 
     id_type!(u32, Customer);
     id_type!(u32, Order);
@@ -47,4 +50,823 @@ mod tests {
         assert_eq!(id.to_string(), t_id.to_string());
         assert_eq!(format!("{t_id:?}"), String::from("TypedId(42)"));
     }
+
+    #[test]
+    fn inline_marker() {
+        let order = Order {
+            id: 42u32.as_id::<Order>(),
+        };
+        let customer = Customer {
+            id: 7u32.as_id::<Customer>(),
+            orders: vec![order.id],
+        };
+
+        assert_eq!(7, *customer.id);
+        assert!(customer.has_order(42u32.as_id::<Order>()));
+        assert!(!customer.has_order(7u32.as_id::<Order>()));
+    }
+
+    type SlugId = typed_id::TypedId<String, Customer>;
+
+    fn owned_id(id: impl Into<SlugId>) -> SlugId {
+        id.into()
+    }
+
+    #[test]
+    fn from_references() {
+        let slug = String::from("jane-doe");
+        let t_slug: SlugId = slug.clone().into();
+
+        assert_eq!(owned_id(&t_slug), t_slug);
+        assert_eq!(owned_id(&slug), t_slug);
+        assert_eq!(owned_id(slug), t_slug);
+    }
+
+    const fn squares<const N: usize>() -> [u32; N] {
+        let mut table = [0; N];
+        let mut i = 0;
+        while i < N {
+            let id = CustomerId::new(i as u32);
+            table[id.into_inner() as usize] = id.into_inner() * *id.get_ref();
+            i += 1;
+        }
+        table
+    }
+
+    #[test]
+    fn const_decomposition() {
+        const TABLE: [u32; 4] = squares();
+        assert_eq!(TABLE, [0, 1, 4, 9]);
+    }
+
+    #[test]
+    fn deliberate_reinterpret() {
+        let customer_id: CustomerId = 42.into();
+        let order_id = customer_id.reinterpret::<Order>();
+        let order = Order { id: order_id };
+
+        let customer = Customer {
+            id: customer_id,
+            orders: vec![order.id],
+        };
+        assert!(customer.has_order(customer.id.reinterpret()));
+    }
+
+    #[test]
+    fn sorting() {
+        let mut ids: Vec<CustomerId> = [4, 2, 5, 1, 3].into_iter().map(Into::into).collect();
+        let asc: Vec<CustomerId> = (1..=5).map(Into::into).collect();
+        let desc: Vec<CustomerId> = (1..=5).rev().map(Into::into).collect();
+
+        ids.sort_by_id();
+        assert_eq!(ids, asc);
+        ids.sort_by_id_desc();
+        assert_eq!(ids, desc);
+        ids.sort_unstable_by_id();
+        assert_eq!(ids, asc);
+    }
+
+    #[test]
+    fn from_str() {
+        let t_id: CustomerId = "42".parse().unwrap();
+        assert_eq!(t_id, 42.into());
+        assert!("forty two".parse::<CustomerId>().is_err());
+    }
+
+    struct Invoice;
+    id_type!(u64, Invoice, "inv_");
+
+    #[test]
+    fn prefixed_display() {
+        let id: InvoiceId = 42.into();
+        assert_eq!(format!("{}", id.prefixed()), "42");
+        assert_eq!(format!("{:#}", id.prefixed()), "inv_42");
+        assert_eq!(id.to_string(), "42");
+    }
+
+    #[test]
+    fn optional_ids() {
+        assert_eq!(Some(42u32).typed::<Customer>(), Some(CustomerId::new(42)));
+        assert_eq!(None::<u32>.typed::<Customer>(), None);
+
+        let ok: Result<u32, ()> = Ok(42);
+        assert_eq!(ok.typed::<Customer>(), Ok(CustomerId::new(42)));
+        let err: Result<u32, ()> = Err(());
+        assert_eq!(err.typed::<Customer>(), Err(()));
+    }
+
+    #[test]
+    fn lazy_iterator() {
+        let ids = vec![1u32, 2, 3, 4, 5].into_iter().typed::<Customer>();
+        assert_eq!(ids.size_hint(), (5, Some(5)));
+        assert_eq!(ids.len(), 5);
+
+        let mut filtered = (1u32..=5).filter(|i| i % 2 == 1).typed::<Customer>();
+        assert_eq!(filtered.size_hint(), (0, Some(5)));
+        assert_eq!(filtered.next(), Some(CustomerId::new(1)));
+
+        let rev: Vec<CustomerId> = (1..=3).typed().rev().collect();
+        assert_eq!(rev, [3.into(), 2.into(), 1.into()]);
+    }
+
+    #[test]
+    fn widened_equality() {
+        let narrow: CustomerId = 42.into();
+        let wide: typed_id::TypedId<u64, Customer> = 42.into();
+        let other: typed_id::TypedId<u64, Customer> = (u32::MAX as u64 + 42).into();
+
+        assert!(narrow.eq_widened(&wide));
+        assert!(wide.eq_widened(&narrow));
+        assert!(!narrow.eq_widened(&other));
+    }
+
+    #[test]
+    fn extend_typed_vec() {
+        let mut ids: typed_id::TypedVec<u32, Customer> = (1..=2).collect();
+        ids.extend([3, 4]);
+        ids.extend((5..=6).typed::<Customer>());
+
+        let expected: Vec<CustomerId> = (1..=6).map(Into::into).collect();
+        assert_eq!(*ids, expected);
+        assert_eq!(ids.into_inner(), expected);
+    }
+
+    #[test]
+    fn erased_ids() {
+        use std::collections::HashSet;
+        use typed_id::AnyId;
+
+        let mut ids: HashSet<Box<dyn AnyId>> = HashSet::new();
+        assert!(ids.insert(Box::new(CustomerId::new(42))));
+        assert!(ids.insert(Box::new(OrderId::new(42))));
+        assert!(ids.insert(Box::new(typed_id::TypedId::<u64, Customer>::new(42))));
+        assert!(!ids.insert(Box::new(CustomerId::new(42))));
+        assert_eq!(ids.len(), 3);
+
+        let customer: Box<dyn AnyId> = Box::new(CustomerId::new(42));
+        assert!(ids.contains(&customer));
+        assert_eq!(
+            customer.as_any().downcast_ref::<CustomerId>(),
+            Some(&CustomerId::new(42))
+        );
+    }
+
+    #[test]
+    fn grouped_erased_ids() {
+        use std::any::TypeId;
+        use typed_id::{group_by_type, AnyId};
+
+        let ids: Vec<Box<dyn AnyId>> = vec![
+            Box::new(CustomerId::new(1)),
+            Box::new(OrderId::new(1)),
+            Box::new(CustomerId::new(2)),
+            Box::new(OrderId::new(3)),
+            Box::new(CustomerId::new(4)),
+        ];
+        let groups = group_by_type(ids);
+        assert_eq!(groups.len(), 2);
+
+        let customers: Vec<_> = groups[&TypeId::of::<Customer>()]
+            .iter()
+            .map(|id| *id.as_any().downcast_ref::<CustomerId>().unwrap())
+            .collect();
+        assert_eq!(customers, [1.into(), 2.into(), 4.into()]);
+
+        let orders: Vec<_> = groups[&TypeId::of::<Order>()]
+            .iter()
+            .map(|id| *id.as_any().downcast_ref::<OrderId>().unwrap())
+            .collect();
+        assert_eq!(orders, [1.into(), 3.into()]);
+    }
+
+    #[test]
+    fn index_typed_map() {
+        use typed_id::TypedMap;
+
+        let mut names: TypedMap<Customer, &str> = [(1.into(), "Jane"), (2.into(), "John")]
+            .into_iter()
+            .collect();
+        assert_eq!(names[CustomerId::new(1)], "Jane");
+        names[CustomerId::new(2)] = "Joan";
+        assert_eq!(names[CustomerId::new(2)], "Joan");
+        assert_eq!(names.len(), 2);
+
+        let emails: TypedMap<Customer, &str, String> =
+            [("jane".to_owned().into(), "jane@example.com")]
+                .into_iter()
+                .collect();
+        assert_eq!(emails["jane".to_owned().into()], "jane@example.com");
+    }
+
+    #[test]
+    #[should_panic(expected = "no Customer with id 3")]
+    fn index_typed_map_missing() {
+        let names: typed_id::TypedMap<Customer, &str> = [(1.into(), "Jane")].into_iter().collect();
+        let _ = names[CustomerId::new(3)];
+    }
+
+    #[test]
+    fn non_zero_conversions() {
+        use std::num::NonZeroU32;
+
+        let zero: CustomerId = 0.into();
+        assert_eq!(zero.try_into_nonzero(), None);
+
+        let id: CustomerId = 42.into();
+        let non_zero = id.try_into_nonzero().unwrap();
+        assert_eq!(*non_zero, NonZeroU32::new(42).unwrap());
+        assert_eq!(non_zero.into_primitive(), id);
+    }
+
+    #[test]
+    fn checked_differences() {
+        let earlier = CustomerId::new(3);
+        let later = CustomerId::new(10);
+        assert_eq!(later.checked_distance(earlier), Some(7));
+        assert_eq!(later.checked_distance(later), Some(0));
+        assert_eq!(earlier.checked_distance(later), None);
+
+        let low = typed_id::TypedId::<i8, Customer>::new(-100);
+        assert_eq!(low.checked_distance(typed_id::TypedId::new(100)), None);
+
+        // The underlying id's `checked_sub` is still reachable through `Deref`
+        assert_eq!(later.checked_sub(5), Some(5));
+    }
+
+    #[test]
+    fn saturating_exit_codes() {
+        assert_eq!(CustomerId::new(0).as_u8_saturating(), 0);
+        assert_eq!(CustomerId::new(42).as_u8_saturating(), 42);
+        assert_eq!(CustomerId::new(255).as_u8_saturating(), 255);
+        assert_eq!(CustomerId::new(256).as_u8_saturating(), 255);
+        assert_eq!(CustomerId::new(u32::MAX).as_u8_saturating(), 255);
+
+        let negative = typed_id::TypedId::<i64, Customer>::new(-1);
+        assert_eq!(negative.as_u8_saturating(), 0);
+    }
+
+    #[test]
+    fn saturating_indices() {
+        use typed_id::TypedId;
+
+        let names = ["Jane", "John"];
+        assert_eq!(
+            names.get(CustomerId::new(1).saturating_index()),
+            Some(&"John")
+        );
+        assert_eq!(TypedId::<u64, Customer>::new(7).saturating_index(), 7);
+
+        // `u128` is wider than `usize` on every target, like `u64` is on 32-bit targets
+        let wide = TypedId::<u128, Customer>::new(u128::MAX);
+        assert_eq!(wide.saturating_index(), usize::MAX);
+        assert_eq!(names.get(wide.saturating_index()), None);
+        let fits = TypedId::<u128, Customer>::new(usize::MAX as u128);
+        assert_eq!(fits.saturating_index(), usize::MAX);
+
+        assert_eq!(TypedId::<i32, Customer>::new(-5).saturating_index(), 0);
+    }
+
+    impl typed_id::DefaultId<u32> for Order {
+        fn default_id() -> u32 {
+            u32::MAX
+        }
+    }
+
+    #[test]
+    fn marker_defaults() {
+        assert_eq!(*OrderId::marker_default(), u32::MAX);
+        assert_eq!(*OrderId::default(), 0);
+        assert_eq!(*CustomerId::default(), 0);
+    }
+
+    #[test]
+    fn dedup_in_order() {
+        let mut ids: Vec<CustomerId> = [1, 2, 1, 3, 2].into_iter().map(Into::into).collect();
+        ids.dedup_preserve_order();
+        assert_eq!(ids, [1.into(), 2.into(), 3.into()]);
+
+        let mut slugs: Vec<SlugId> = ["b", "a", "b"].map(|s| s.to_owned().into()).into();
+        slugs.dedup_preserve_order();
+        assert_eq!(slugs, ["b".to_owned().into(), "a".to_owned().into()]);
+    }
+
+    #[test]
+    fn zero_sized_marker() {
+        use std::mem::{align_of, size_of};
+
+        assert_eq!(size_of::<CustomerId>(), size_of::<u32>());
+        assert_eq!(align_of::<CustomerId>(), align_of::<u32>());
+        assert_eq!(size_of::<Option<CustomerId>>(), size_of::<Option<u32>>());
+    }
+
+    fn slug_len(id: impl AsRef<SlugId>) -> usize {
+        id.as_ref().len()
+    }
+
+    #[test]
+    fn as_ref_acceptor() {
+        let t_slug: SlugId = String::from("jane-doe").into();
+        assert_eq!(slug_len(&t_slug), 8);
+        assert_eq!(slug_len(t_slug), 8);
+    }
+
+    #[test]
+    fn replace_inner() {
+        let mut t_slug: SlugId = String::from("jane-doe").into();
+        let old = t_slug.replace_inner(String::from("jane-smith"));
+        assert_eq!(old, "jane-doe");
+        assert_eq!(*t_slug, "jane-smith");
+
+        // `str::replace` is still reachable through `Deref`
+        assert_eq!(t_slug.replace("smith", "doe"), "jane-doe");
+    }
+
+    #[test]
+    fn borrowed_lookups() {
+        use std::collections::{HashMap, HashSet};
+
+        let names: HashMap<SlugId, u32> = [("jane-doe", 1), ("john-doe", 2)]
+            .into_iter()
+            .map(|(slug, n)| (String::from(slug).into(), n))
+            .collect();
+        assert_eq!(names.get("jane-doe"), Some(&1));
+        assert_eq!(names.get("jane-smith"), None);
+
+        let keys: HashSet<typed_id::TypedId<Vec<u8>, Customer>> = [vec![1u8, 2], vec![3]]
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        assert!(keys.contains(&[1u8, 2][..]));
+        assert!(!keys.contains(&[2u8][..]));
+    }
+
+    typed_id::define_entity! {
+        struct Product {
+            name: String,
+        }
+    }
+
+    typed_id::define_entity! {
+        u64,
+        #[derive(Debug, PartialEq)]
+        struct Review {
+            product: ProductId,
+            stars: u8,
+        }
+    }
+
+    fn entity_id<E: typed_id::Entity>(raw: u32) -> E::Id
+    where
+        E::Id: From<u32>,
+    {
+        raw.into()
+    }
+
+    #[test]
+    fn defined_entities() {
+        let product = Product {
+            name: String::from("Widget"),
+        };
+        let product_id: ProductId = entity_id::<Product>(42);
+        assert_eq!(*product_id, 42u32);
+        assert_eq!(product.name, "Widget");
+
+        let review = Review {
+            product: product_id,
+            stars: 5,
+        };
+        let review_id: <Review as typed_id::Entity>::Id = ReviewId::new(u64::MAX);
+        assert_eq!(*review_id, u64::MAX);
+        assert_eq!(review.product, product_id);
+        assert_eq!(review.stars, 5);
+    }
+
+    #[test]
+    fn widening_into_ints() {
+        assert_eq!(u64::from(typed_id::TypedId::<u32, Customer>::new(5)), 5);
+        assert_eq!(u128::from(CustomerId::new(u32::MAX)), u32::MAX as u128);
+        assert_eq!(usize::from(typed_id::TypedId::<u16, Customer>::new(5)), 5);
+        assert_eq!(u32::from(CustomerId::new(5)), 5);
+    }
+
+    fn parse_id<'a, Id: TryFrom<&'a str>>(s: &'a str) -> Option<Id> {
+        Id::try_from(s).ok()
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(CustomerId::try_from("42"), Ok(42.into()));
+        assert!(CustomerId::try_from("-42").is_err());
+        assert_eq!(parse_id::<CustomerId>("42"), Some(42.into()));
+        assert_eq!(
+            parse_id::<SlugId>("jane-doe"),
+            Some(String::from("jane-doe").into())
+        );
+    }
+
+    struct Shipment;
+    id_type!(u64, Shipment, default = 1000);
+
+    #[test]
+    fn macro_default() {
+        assert_eq!(*ShipmentId::marker_default(), 1000);
+        assert_eq!(*ShipmentId::default(), 0);
+    }
+
+    #[allow(dead_code)]
+    struct Port;
+    id_type!(u16, Port, range = 1..=1023);
+
+    #[test]
+    fn macro_range() {
+        use typed_id::OutOfRange;
+
+        assert_eq!(PortId::try_new(1), Ok(PortId::new(1)));
+        assert_eq!(PortId::try_new(1023), Ok(PortId::new(1023)));
+        assert_eq!(PortId::try_new(0), Err(OutOfRange { id: 0 }));
+        let err = PortId::try_new(8080).unwrap_err();
+        assert_eq!(err.to_string(), "id 8080 is out of range");
+
+        // `new` stays unchecked, and `Debug` is unchanged
+        assert_eq!(format!("{:?}", PortId::new(8080)), "TypedId(8080)");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn nested_id_path() {
+        use serde::{Deserialize, Serialize};
+
+        typed_id::from_path!(data_id, "data.id");
+
+        #[derive(Serialize, Deserialize, Debug)]
+        struct Envelope {
+            #[serde(with = "data_id")]
+            customer: CustomerId,
+        }
+
+        let json = r#"{"customer":{"meta":{"id":7},"data":{"name":"Jane","id":42,"tags":[1]}}}"#;
+        let envelope: Envelope = serde_json::from_str(json).unwrap();
+        assert_eq!(envelope.customer, CustomerId::new(42));
+        assert_eq!(
+            serde_json::to_string(&envelope).unwrap(),
+            r#"{"customer":{"data":{"id":42}}}"#
+        );
+
+        let err = serde_json::from_str::<Envelope>(r#"{"customer":{"data":{"name":"Jane"}}}"#)
+            .unwrap_err();
+        assert!(err.to_string().starts_with("missing field `id`"));
+        assert!(serde_json::from_str::<Envelope>(r#"{"customer":{"data":42}}"#).is_err());
+    }
+
+    #[test]
+    fn sort_by_typed_key() {
+        #[derive(Debug, PartialEq)]
+        struct Record {
+            status: u8,
+            id: CustomerId,
+        }
+
+        let record = |status, id: u32| Record {
+            status,
+            id: id.into(),
+        };
+        let mut records = vec![record(1, 7), record(0, 9), record(1, 2), record(0, 3)];
+        records.sort_by_key(|r| (r.status, r.id));
+        assert_eq!(
+            records,
+            [record(0, 3), record(0, 9), record(1, 2), record(1, 7)]
+        );
+
+        records.sort_by_key(|r| r.id);
+        let ids: Vec<_> = records.iter().map(|r| r.id.into_inner()).collect();
+        assert_eq!(ids, [2, 3, 7, 9]);
+
+        records.sort_by(|a, b| b.id.as_sort_key().cmp(a.id.as_sort_key()));
+        assert_eq!(records[0].id.as_sort_key(), &9);
+    }
+
+    #[test]
+    fn project_joint_ids() {
+        type MembershipId = typed_id::TypedId<u32, (Customer, Order)>;
+
+        let row = MembershipId::new(42);
+        let customer: CustomerId = row.project_left();
+        assert_eq!(customer, CustomerId::new(42));
+        let order: OrderId = row.project_right();
+        assert_eq!(order, OrderId::new(42));
+
+        let rows = [MembershipId::new(1), MembershipId::new(2)];
+        let orders: Vec<OrderId> = rows.into_iter().map(MembershipId::project_right).collect();
+        assert_eq!(orders, [OrderId::new(1), OrderId::new(2)]);
+    }
+
+    typed_id::well_known_ids! { Customer { ANONYMOUS = 0, SYSTEM = 1 } }
+
+    #[test]
+    fn well_known() {
+        const SYSTEM: CustomerId = CustomerWellKnown::SYSTEM;
+
+        assert_eq!(CustomerWellKnown::ANONYMOUS, CustomerId::new(0));
+        assert_eq!(SYSTEM, CustomerId::new(1));
+        let customer = Customer {
+            id: CustomerWellKnown::ANONYMOUS,
+            orders: Vec::new(),
+        };
+        assert_eq!(customer.id, CustomerWellKnown::ANONYMOUS);
+    }
+
+    #[test]
+    fn pretty_debug() {
+        let id = typed_id::TypedId::<(u32, u32), Customer>::new((4, 2));
+        assert_eq!(format!("{id:?}"), "TypedId((4, 2))");
+        assert_eq!(
+            format!("{id:#?}"),
+            "TypedId(\n    (\n        4,\n        2,\n    ),\n)"
+        );
+    }
+
+    #[allow(dead_code)]
+    struct Session;
+    id_type!(uuid::Uuid, Session, default = uuid::Uuid::nil());
+
+    #[test]
+    fn macro_expression_default() {
+        assert!(SessionId::marker_default().is_nil());
+        assert_eq!(SessionId::marker_default(), SessionId::default());
+    }
+
+    #[test]
+    fn presized_map() {
+        let map = typed_id::collect_map_with_capacity(
+            (0..10)
+                .filter(|i| i % 2 == 0)
+                .map(|i| (CustomerId::new(i), i * 10)),
+            64,
+        );
+        assert!(map.capacity() >= 64);
+        assert_eq!(map.len(), 5);
+        assert_eq!(map[&CustomerId::new(4)], 40);
+    }
+
+    #[test]
+    fn str_cow() {
+        use std::borrow::Cow;
+
+        let slug: SlugId = "jane".into();
+        assert!(matches!(slug.as_str_cow(), Cow::Borrowed("jane")));
+        assert_eq!(slug.as_str_cow().as_ptr(), slug.as_ptr());
+
+        let id: CustomerId = 42.into();
+        assert!(matches!(id.as_str_cow(), Cow::Owned(s) if s == "42"));
+    }
+
+    #[test]
+    fn strip_prefix() {
+        let id: SlugId = "cust_jane".into();
+        assert!(id.has_id_prefix("cust_"));
+        let stripped: SlugId = id.strip_id_prefix("cust_").unwrap();
+        assert_eq!(stripped, SlugId::from("jane"));
+
+        assert!(!id.has_id_prefix("ord_"));
+        assert!(id.strip_id_prefix("ord_").is_none());
+    }
+
+    #[test]
+    fn shared_pointers() {
+        use std::{rc::Rc, sync::Arc};
+
+        let id: CustomerId = 42.into();
+        let arc: Arc<u32> = id.into();
+        assert_eq!(*arc, 42);
+        let rc: Rc<u32> = id.into();
+        assert_eq!(*rc, 42);
+        assert_eq!(*id.into_box(), 42);
+
+        let shared = arc.clone();
+        assert_eq!(CustomerId::from_arc(arc), id);
+        assert_eq!(CustomerId::from_arc(shared), id);
+    }
+
+    #[test]
+    fn zipped_ids() {
+        let customers: Vec<CustomerId> = (1..=3).typed().collect();
+        let orders: Vec<OrderId> = (10..=12).typed().collect();
+        let pairs = zip_ids(customers.clone(), orders).unwrap();
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs[2], (CustomerId::new(3), OrderId::new(12)));
+
+        let err = zip_ids(customers, vec![OrderId::new(10)]).unwrap_err();
+        assert_eq!(err, ZipLengthError { left: 3, right: 1 });
+
+        let (raw,): (u32,) = CustomerId::new(42).into();
+        assert_eq!(raw, 42);
+    }
+
+    assert_distinct_ids!(CustomerId, OrderId);
+
+    #[test]
+    fn distinct_markers() {
+        assert_distinct_ids!(CustomerId, OrderId);
+        assert_distinct_ids!(InvoiceId, ShipmentId);
+    }
+
+    #[test]
+    fn joined_display() {
+        let ids: Vec<CustomerId> = (1..=3).typed().collect();
+        assert_eq!(ids.iter().copied().join_display(", "), "1, 2, 3");
+        assert_eq!(ids.into_iter().take(0).join_display(", "), "");
+    }
+
+    #[test]
+    fn raw_comparisons() {
+        use std::cmp::Ordering;
+
+        let id = CustomerId::new(42);
+        let raw: u32 = 42;
+        assert!(id.inner_eq(&raw));
+        assert!(!id.inner_eq(&7));
+        assert_eq!(id.inner_cmp(&raw), Ordering::Equal);
+        assert_eq!(id.inner_cmp(&100), Ordering::Less);
+    }
+
+    #[test]
+    fn marker_type_name() {
+        assert!(CustomerId::type_name().contains("Customer"));
+        assert!(OrderId::type_name().ends_with("::Order"));
+    }
+
+    #[test]
+    fn cow_conversions() {
+        use std::borrow::Cow;
+
+        let id = SlugId::new("jane".to_owned());
+        let borrowed = id.as_cow();
+        assert!(matches!(borrowed, Cow::Borrowed(_)));
+        assert_eq!(*borrowed, "jane");
+
+        let owned: Cow<'_, u32> = CustomerId::new(42).into();
+        assert!(matches!(owned, Cow::Owned(42)));
+    }
+
+    fn double(mut id: impl AsMut<u32>) -> u32 {
+        *id.as_mut() *= 2;
+        *id.as_mut()
+    }
+
+    #[test]
+    fn as_mut_acceptor() {
+        let mut id = CustomerId::new(21);
+        assert_eq!(double(&mut id), 42);
+        assert_eq!(*id, 42);
+    }
+
+    #[test]
+    fn exact_bytes() {
+        use typed_id::LenError;
+
+        let id = CustomerId::from_bytes_exact(&42u32.to_be_bytes()).unwrap();
+        assert_eq!(id, CustomerId::new(42));
+
+        let short = LenError {
+            expected: 4,
+            got: 3,
+        };
+        assert_eq!(CustomerId::from_bytes_exact(&[0, 0, 42]), Err(short));
+
+        let err = CustomerId::from_bytes_exact(&[0, 0, 0, 0, 42]).unwrap_err();
+        assert_eq!((err.expected, err.got), (4, 5));
+        assert_eq!(err.to_string(), "expected exactly 4 bytes for an id, got 5");
+
+        let id = typed_id::TypedId::<[u8; 2], Order>::from_bytes_exact(&[1, 2]).unwrap();
+        assert_eq!(*id, [1, 2]);
+    }
+
+    #[test]
+    fn raw_range_queries() {
+        use std::collections::BTreeMap;
+        use typed_id::TypedBTreeExt;
+
+        let map: BTreeMap<CustomerId, u32> = (0..30).map(|i| (CustomerId::new(i), i * 2)).collect();
+        let ids: Vec<CustomerId> = map
+            .range_by_inner(10u32..20u32)
+            .map(|(id, _)| *id)
+            .collect();
+        assert_eq!(ids, (10..20).typed().collect::<Vec<_>>());
+
+        let values: Vec<u32> = map.range_by_inner(..=2).map(|(_, v)| *v).collect();
+        assert_eq!(values, [0, 2, 4]);
+        assert_eq!(map.range_by_inner(40..).count(), 0);
+    }
+
+    #[test]
+    fn retyped_collections() {
+        let customers: Vec<CustomerId> = (1..=3).typed().collect();
+        let ptr = customers.as_ptr() as usize;
+        let cap = customers.capacity();
+
+        let orders: Vec<OrderId> = CustomerId::retype_vec(customers);
+        assert_eq!(orders.as_ptr() as usize, ptr);
+        assert_eq!(orders.capacity(), cap);
+        assert_eq!(orders, (1..=3).typed().collect::<Vec<OrderId>>());
+
+        let customers: &[CustomerId] = OrderId::retype_slice(&orders);
+        assert_eq!(customers.as_ptr() as usize, ptr);
+        assert_eq!(customers, (1..=3).typed().collect::<Vec<CustomerId>>());
+    }
+
+    #[test]
+    fn explicit_duplicate() {
+        let id = SlugId::new("jane-doe".to_owned());
+        let copy = id.duplicate();
+        assert_eq!(copy, id);
+        assert_ne!(copy.as_ptr(), id.as_ptr());
+    }
+
+    #[test]
+    fn raw_key_search() {
+        let ids: Vec<CustomerId> = (0..10).map(|i| i * 3).typed().collect();
+        assert_eq!(ids.binary_search_by(|id| id.inner_cmp(&12)), Ok(4));
+        assert_eq!(ids.binary_search_by(|id| id.inner_cmp(&13)), Err(5));
+    }
+
+    fn first_copied(ids: &[CustomerId]) -> Option<CustomerId> {
+        ids.first().map(CustomerId::copied)
+    }
+
+    #[test]
+    fn copied_from_ref() {
+        let mut id = CustomerId::new(42);
+        let id_ref: &mut CustomerId = &mut id;
+        let copy = id_ref.copied();
+        id_ref.replace_inner(7);
+        assert_eq!(copy, CustomerId::new(42));
+        assert_eq!(first_copied(&[id]), Some(CustomerId::new(7)));
+    }
+
+    typed_id::define_entity! {
+        repository,
+        #[derive(Clone, Debug, PartialEq)]
+        struct Supplier {
+            name: String,
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryStore {
+        suppliers: std::collections::HashMap<SupplierId, Supplier>,
+        next: u32,
+    }
+
+    impl typed_id::Repository<Supplier> for MemoryStore {
+        fn get(&self, id: SupplierId) -> Option<Supplier> {
+            self.suppliers.get(&id).cloned()
+        }
+
+        fn insert(&mut self, supplier: Supplier) -> SupplierId {
+            let id = SupplierId::new(self.next);
+            self.next += 1;
+            self.suppliers.insert(id, supplier);
+            id
+        }
+    }
+
+    fn register(repo: &mut impl SupplierRepository, name: &str) -> SupplierId {
+        repo.insert(Supplier { name: name.into() })
+    }
+
+    #[test]
+    fn generated_repository() {
+        use typed_id::Repository;
+
+        let mut store = MemoryStore::default();
+        let acme = register(&mut store, "Acme");
+        let globex = register(&mut store, "Globex");
+        assert_ne!(acme, globex);
+        assert_eq!(store.get(acme).unwrap().name, "Acme");
+        assert_eq!(store.get(globex).unwrap().name, "Globex");
+        assert_eq!(store.get(SupplierId::new(7)), None);
+    }
+
+    #[test]
+    fn composite_ids() {
+        use typed_id::ParseCompositeError;
+
+        type DocumentId = typed_id::TypedId<(u32, u32), Product>;
+
+        let id: DocumentId = (7, 42).into();
+        assert_eq!((*id.part0(), *id.part1()), (7, 42));
+
+        let s = id.display_composite().to_string();
+        assert_eq!(s, "7:42");
+        assert_eq!(DocumentId::parse_composite(&s).unwrap(), id);
+
+        assert_eq!(
+            DocumentId::parse_composite("742"),
+            Err(ParseCompositeError::MissingSeparator)
+        );
+        assert!(matches!(
+            DocumentId::parse_composite("7:x"),
+            Err(ParseCompositeError::Part1(_))
+        ));
+    }
 }